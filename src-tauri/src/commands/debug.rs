@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 use std::process::{Child, Command, Stdio};
 use std::sync::Arc;
 use tauri::{Emitter, State, Window};
@@ -20,6 +21,9 @@ use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::Mutex;
 
+use super::project::venv_python;
+use super::symbols::executable_lines;
+
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
 
@@ -32,10 +36,19 @@ pub fn create_debug_manager() -> DebugSessionManager {
 
 // Debug session structure
 pub struct DebugSession {
-    stream: Option<TcpStream>,
+    // Kept buffered across reads so bytes of the next message aren't lost
+    stream: Option<BufReader<TcpStream>>,
     process: Option<Child>,
     seq: u64,
+    host: String,
     port: u16,
+    source_cache: HashMap<u32, String>,
+    // Events that arrived while waiting for a response, for the event loop
+    pending_events: VecDeque<serde_json::Value>,
+    // setDataBreakpoints replaces the whole set, so keep what has been added
+    data_breakpoints: Vec<serde_json::Value>,
+    // The adapter's reply to `initialize`
+    capabilities: serde_json::Value,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -46,6 +59,12 @@ pub struct Breakpoint {
     pub verified: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DebugThread {
+    pub id: u32,
+    pub name: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct StackFrame {
     pub id: u32,
@@ -53,6 +72,7 @@ pub struct StackFrame {
     pub file: String,
     pub line: u32,
     pub column: u32,
+    pub source_reference: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -62,6 +82,80 @@ pub struct Variable {
     #[serde(rename = "type")]
     pub type_: String,
     pub variables_reference: u32,
+    pub indexed_variables: Option<u32>,
+    pub named_variables: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VariableRepr {
+    pub name: String,
+    pub repr: String,
+    pub indexed_variables: Option<u32>,
+    pub named_variables: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VariableInspection {
+    pub name: String,
+    // The expression that was formatted, e.g. `data['items'][0]` for a child
+    pub expression: String,
+    pub value: String,
+    // Syntax highlighting for `value`
+    pub language: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BreakpointLineCheck {
+    pub line: u32,
+    pub valid: bool,
+    // Closest executable line when `line` isn't one
+    pub nearest: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExceptionBreakpointFilter {
+    pub filter: String,
+    pub label: String,
+    pub default: bool,
+}
+
+// The subset of the adapter's capabilities the UI acts on
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DebugCapabilities {
+    pub supports_conditional_breakpoints: bool,
+    pub supports_hit_conditional_breakpoints: bool,
+    pub supports_log_points: bool,
+    pub supports_function_breakpoints: bool,
+    pub supports_data_breakpoints: bool,
+    pub supports_step_back: bool,
+    pub supports_restart_frame: bool,
+    pub supports_goto_targets_request: bool,
+    pub supports_step_in_targets_request: bool,
+    pub supports_set_variable: bool,
+    pub supports_set_expression: bool,
+    pub supports_completions_request: bool,
+    pub supports_evaluate_for_hovers: bool,
+    pub supports_clipboard_context: bool,
+    pub supports_exception_info_request: bool,
+    pub supports_terminate_request: bool,
+    pub supports_restart_request: bool,
+    pub exception_breakpoint_filters: Vec<ExceptionBreakpointFilter>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WatchResult {
+    pub expression: String,
+    pub result: Option<String>,
+    // Evaluation error such as a NameError; the other watches still run
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DataBreakpoint {
+    pub data_id: String,
+    pub description: String,
+    pub verified: bool,
+    pub message: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -73,19 +167,28 @@ pub struct Scope {
 
 impl DebugSession {
     pub fn new(port: u16) -> Self {
+        Self::with_host("127.0.0.1", port)
+    }
+
+    pub fn with_host(host: &str, port: u16) -> Self {
         Self {
             stream: None,
             process: None,
             seq: 1,
+            host: host.to_string(),
             port,
+            source_cache: HashMap::new(),
+            pending_events: VecDeque::new(),
+            data_breakpoints: Vec::new(),
+            capabilities: serde_json::Value::Null,
         }
     }
 
     pub async fn connect(&mut self) -> Result<(), String> {
-        let stream = TcpStream::connect(format!("127.0.0.1:{}", self.port))
+        let stream = TcpStream::connect(format!("{}:{}", self.host, self.port))
             .await
             .map_err(|e| format!("Failed to connect to debugpy: {}", e))?;
-        self.stream = Some(stream);
+        self.stream = Some(BufReader::new(stream));
         Ok(())
     }
 
@@ -122,64 +225,73 @@ impl DebugSession {
     }
 
     async fn read_response(&mut self) -> Result<serde_json::Value, String> {
-        let stream = self
-            .stream
-            .as_mut()
-            .ok_or("Not connected to debug adapter")?;
-
-        let mut reader = BufReader::new(stream);
-
         loop {
-            // Read Content-Length header
-            let mut header = String::new();
-            loop {
-                let mut line = String::new();
-                reader
-                    .read_line(&mut line)
-                    .await
-                    .map_err(|e| format!("Failed to read header: {}", e))?;
-
-                if line == "\r\n" {
-                    break;
-                }
-                header.push_str(&line);
-            }
-
-            // Parse Content-Length
-            let content_length: usize = header
-                .lines()
-                .find(|l| l.starts_with("Content-Length:"))
-                .and_then(|l| l.split(':').nth(1))
-                .and_then(|s| s.trim().parse().ok())
-                .ok_or("Missing Content-Length header")?;
-
-            // Read JSON body
-            let mut body = vec![0u8; content_length];
-            reader
-                .read_exact(&mut body)
-                .await
-                .map_err(|e| format!("Failed to read body: {}", e))?;
-
-            let message: serde_json::Value =
-                serde_json::from_slice(&body).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+            let message = self.read_message().await?;
 
             // Check if this is a response or an event
             let msg_type = message["type"].as_str().unwrap_or("");
 
-            if msg_type == "response" {
-                // This is a response, return it
-                return Ok(message);
-            } else if msg_type == "event" {
-                // This is an event, log it and continue reading
-                println!("[DEBUG] Skipping event during response read: {}", message["event"].as_str().unwrap_or("unknown"));
-                // Continue loop to read the next message
+            if msg_type == "event" {
+                // Keep it for the event loop and continue reading
+                println!("[DEBUG] Queueing event during response read: {}", message["event"].as_str().unwrap_or("unknown"));
+                self.pending_events.push_back(message);
             } else {
-                // Unknown message type
+                // A response, or an unknown message type
                 return Ok(message);
             }
         }
     }
 
+    // Next message for the event loop, starting with any queued events
+    async fn next_message(&mut self) -> Result<serde_json::Value, String> {
+        match self.pending_events.pop_front() {
+            Some(event) => Ok(event),
+            None => self.read_message().await,
+        }
+    }
+
+    async fn read_message(&mut self) -> Result<serde_json::Value, String> {
+        let reader = self
+            .stream
+            .as_mut()
+            .ok_or("Not connected to debug adapter")?;
+
+        // Read Content-Length header
+        let mut header = String::new();
+        loop {
+            let mut line = String::new();
+            let read = reader
+                .read_line(&mut line)
+                .await
+                .map_err(|e| format!("Failed to read header: {}", e))?;
+            if read == 0 {
+                return Err("Debug adapter closed the connection".to_string());
+            }
+
+            if line == "\r\n" {
+                break;
+            }
+            header.push_str(&line);
+        }
+
+        // Parse Content-Length
+        let content_length: usize = header
+            .lines()
+            .find(|l| l.starts_with("Content-Length:"))
+            .and_then(|l| l.split(':').nth(1))
+            .and_then(|s| s.trim().parse().ok())
+            .ok_or("Missing Content-Length header")?;
+
+        // Read JSON body
+        let mut body = vec![0u8; content_length];
+        reader
+            .read_exact(&mut body)
+            .await
+            .map_err(|e| format!("Failed to read body: {}", e))?;
+
+        serde_json::from_slice(&body).map_err(|e| format!("Failed to parse JSON: {}", e))
+    }
+
     pub fn set_process(&mut self, process: Child) {
         self.process = Some(process);
     }
@@ -215,24 +327,40 @@ async fn find_available_port() -> Result<u16, String> {
     Ok(port)
 }
 
+async fn port_is_available(port: u16) -> bool {
+    TcpListener::bind(("127.0.0.1", port)).await.is_ok()
+}
+
 // Tauri Commands
 
+#[tauri::command]
+pub async fn is_port_available(port: u16) -> Result<bool, String> {
+    Ok(port_is_available(port).await)
+}
+
+#[tauri::command]
+pub async fn find_free_port(start: u16) -> Result<u16, String> {
+    for port in start.max(1)..=u16::MAX {
+        if port_is_available(port).await {
+            return Ok(port);
+        }
+    }
+    Err(format!("No free port found at or after {}", start))
+}
+
 #[tauri::command]
 pub async fn start_debug_session(
     window: Window,
     project_path: String,
     script_path: String,
     breakpoints: Vec<Breakpoint>,
+    stop_on_entry: bool,
     debug_manager: State<'_, DebugSessionManager>,
 ) -> Result<String, String> {
     println!("[DEBUG] Starting debug session for: {}", script_path);
 
     // Check if debugpy is installed
-    let python_exe = if cfg!(target_os = "windows") {
-        format!("{}/.venv/Scripts/python.exe", project_path)
-    } else {
-        format!("{}/.venv/bin/python", project_path)
-    };
+    let python_exe = venv_python(&project_path);
 
     // Verify Python executable exists
     if !std::path::Path::new(&python_exe).exists() {
@@ -325,25 +453,7 @@ pub async fn start_debug_session(
 
     println!("[DEBUG] Connected to debugpy");
 
-    // Initialize DAP session
-    let init_response = session
-        .send_request(
-            "initialize",
-            serde_json::json!({
-                "clientID": "pyra",
-                "clientName": "Pyra IDE",
-                "adapterID": "python",
-                "pathFormat": "path",
-                "linesStartAt1": true,
-                "columnsStartAt1": true,
-                "supportsVariableType": true,
-                "supportsVariablePaging": false,
-                "supportsRunInTerminalRequest": false,
-            }),
-        )
-        .await?;
-
-    println!("[DEBUG] Initialized: {:?}", init_response);
+    initialize_session(&mut session).await?;
 
     // Send launch request - required even with --wait-for-client
     let launch_response = session
@@ -357,13 +467,104 @@ pub async fn start_debug_session(
                 "cwd": project_path,
                 "console": "integratedTerminal",
                 "justMyCode": true,
-                "stopOnEntry": false
+                "stopOnEntry": stop_on_entry
             }),
         )
         .await?;
 
     println!("[DEBUG] Launch: {:?}", launch_response);
 
+    set_breakpoints_and_run(&mut session, &breakpoints).await?;
+    spawn_session(session, window, &debug_manager).await;
+
+    Ok(format!("Debug session started on port {}", port))
+}
+
+#[tauri::command]
+pub async fn attach_debug_session(
+    window: Window,
+    host: String,
+    port: u16,
+    breakpoints: Vec<Breakpoint>,
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<String, String> {
+    println!("[DEBUG] Attaching to debugpy at {}:{}", host, port);
+
+    // The process was started elsewhere with `debugpy --listen`, so there is
+    // nothing to spawn and nothing to kill when the session ends
+    let mut session = DebugSession::with_host(&host, port);
+    session.connect().await.map_err(|e| {
+        format!(
+            "{}\n\nMake sure the process was started with `python -m debugpy --listen {}:{}`",
+            e, host, port
+        )
+    })?;
+
+    println!("[DEBUG] Connected to debugpy");
+
+    initialize_session(&mut session).await?;
+
+    let attach_response = session
+        .send_request(
+            "attach",
+            serde_json::json!({
+                "name": "Python: Attach",
+                "type": "python",
+                "request": "attach",
+                "connect": {
+                    "host": host,
+                    "port": port
+                },
+                "justMyCode": true
+            }),
+        )
+        .await?;
+
+    println!("[DEBUG] Attach: {:?}", attach_response);
+
+    if attach_response["success"].as_bool() == Some(false) {
+        return Err(format!(
+            "Failed to attach: {}",
+            attach_response["message"].as_str().unwrap_or("unknown error")
+        ));
+    }
+
+    set_breakpoints_and_run(&mut session, &breakpoints).await?;
+    spawn_session(session, window, &debug_manager).await;
+
+    Ok(format!("Attached to debug session at {}:{}", host, port))
+}
+
+// Send the DAP initialize request
+async fn initialize_session(session: &mut DebugSession) -> Result<(), String> {
+    let init_response = session
+        .send_request(
+            "initialize",
+            serde_json::json!({
+                "clientID": "pyra",
+                "clientName": "Pyra IDE",
+                "adapterID": "python",
+                "pathFormat": "path",
+                "linesStartAt1": true,
+                "columnsStartAt1": true,
+                "supportsVariableType": true,
+                "supportsVariablePaging": true,
+                "supportsRunInTerminalRequest": false,
+                "supportsDataBreakpoints": true,
+            }),
+        )
+        .await?;
+
+    println!("[DEBUG] Initialized: {:?}", init_response);
+    session.capabilities = init_response["body"].clone();
+    Ok(())
+}
+
+// Set breakpoints, then signal configurationDone so the program starts
+async fn set_breakpoints_and_run(
+    session: &mut DebugSession,
+    breakpoints: &[Breakpoint],
+) -> Result<(), String> {
     // Set breakpoints (group by file)
     println!("[DEBUG] Received {} breakpoints", breakpoints.len());
     if !breakpoints.is_empty() {
@@ -395,7 +596,15 @@ pub async fn start_debug_session(
         .await?;
 
     println!("[DEBUG] Configuration done: {:?}", config_response);
+    Ok(())
+}
 
+// Store the session and listen for its events
+async fn spawn_session(
+    session: DebugSession,
+    window: Window,
+    debug_manager: &DebugSessionManager,
+) {
     // Store session
     {
         let mut manager = debug_manager.lock().await;
@@ -403,12 +612,10 @@ pub async fn start_debug_session(
     }
 
     // Start event loop
-    let manager_clone = Arc::clone(&*debug_manager);
+    let manager_clone = Arc::clone(debug_manager);
     tokio::spawn(async move {
         debug_event_loop(manager_clone, window).await;
     });
-
-    Ok(format!("Debug session started on port {}", port))
 }
 
 #[tauri::command]
@@ -475,6 +682,32 @@ pub async fn debug_step_out(
     }
 }
 
+#[tauri::command]
+pub async fn get_threads(
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<Vec<DebugThread>, String> {
+    let mut manager = debug_manager.lock().await;
+    if let Some(session) = manager.as_mut() {
+        let response = session
+            .send_request("threads", serde_json::json!({}))
+            .await?;
+
+        let threads = response["body"]["threads"]
+            .as_array()
+            .ok_or("Invalid threads response")?
+            .iter()
+            .map(|t| DebugThread {
+                id: t["id"].as_u64().unwrap_or(0) as u32,
+                name: t["name"].as_str().unwrap_or("").to_string(),
+            })
+            .collect();
+
+        Ok(threads)
+    } else {
+        Err("No active debug session".to_string())
+    }
+}
+
 #[tauri::command]
 pub async fn get_stack_trace(
     thread_id: u32,
@@ -496,6 +729,10 @@ pub async fn get_stack_trace(
                 file: f["source"]["path"].as_str().unwrap_or("").to_string(),
                 line: f["line"].as_u64().unwrap_or(0) as u32,
                 column: f["column"].as_u64().unwrap_or(0) as u32,
+                source_reference: f["source"]["sourceReference"]
+                    .as_u64()
+                    .filter(|r| *r > 0)
+                    .map(|r| r as u32),
             })
             .collect();
 
@@ -505,6 +742,57 @@ pub async fn get_stack_trace(
     }
 }
 
+#[tauri::command]
+pub async fn get_source(
+    source_reference: Option<u32>,
+    path: Option<String>,
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<String, String> {
+    // Sources without a reference live on disk (project or venv files)
+    let source_reference = match source_reference.filter(|r| *r > 0) {
+        Some(reference) => reference,
+        None => {
+            let path = path.ok_or("Either a source reference or a path is required")?;
+            return std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read source {}: {}", path, e));
+        }
+    };
+
+    let mut manager = debug_manager.lock().await;
+    let session = manager.as_mut().ok_or("No active debug session")?;
+
+    if let Some(content) = session.source_cache.get(&source_reference) {
+        return Ok(content.clone());
+    }
+
+    let mut source = serde_json::json!({ "sourceReference": source_reference });
+    if let Some(ref path) = path {
+        source["path"] = serde_json::json!(path);
+    }
+    let response = session
+        .send_request(
+            "source",
+            serde_json::json!({
+                "source": source,
+                "sourceReference": source_reference,
+            }),
+        )
+        .await?;
+
+    let content = response["body"]["content"]
+        .as_str()
+        .ok_or_else(|| {
+            response["message"]
+                .as_str()
+                .unwrap_or("Invalid source response")
+                .to_string()
+        })?
+        .to_string();
+
+    session.source_cache.insert(source_reference, content.clone());
+    Ok(content)
+}
+
 #[tauri::command]
 pub async fn get_scopes(
     frame_id: u32,
@@ -536,15 +824,23 @@ pub async fn get_scopes(
 #[tauri::command]
 pub async fn get_variables(
     variables_reference: u32,
+    start: Option<u32>,
+    count: Option<u32>,
     debug_manager: State<'_, DebugSessionManager>,
 ) -> Result<Vec<Variable>, String> {
     let mut manager = debug_manager.lock().await;
     if let Some(session) = manager.as_mut() {
+        let mut arguments = serde_json::json!({ "variablesReference": variables_reference });
+        // Page through large collections instead of fetching every child
+        if let Some(start) = start {
+            arguments["start"] = serde_json::json!(start);
+        }
+        if let Some(count) = count {
+            arguments["count"] = serde_json::json!(count);
+        }
+
         let response = session
-            .send_request(
-                "variables",
-                serde_json::json!({ "variablesReference": variables_reference }),
-            )
+            .send_request("variables", arguments)
             .await?;
 
         let variables = response["body"]["variables"]
@@ -556,6 +852,8 @@ pub async fn get_variables(
                 value: v["value"].as_str().unwrap_or("").to_string(),
                 type_: v["type"].as_str().unwrap_or("").to_string(),
                 variables_reference: v["variablesReference"].as_u64().unwrap_or(0) as u32,
+                indexed_variables: v["indexedVariables"].as_u64().map(|n| n as u32),
+                named_variables: v["namedVariables"].as_u64().map(|n| n as u32),
             })
             .collect();
 
@@ -565,6 +863,349 @@ pub async fn get_variables(
     }
 }
 
+#[tauri::command]
+pub async fn get_variable_repr(
+    variables_reference: u32,
+    name: String,
+    frame_id: Option<u32>,
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<VariableRepr, String> {
+    let mut manager = debug_manager.lock().await;
+    let session = manager.as_mut().ok_or("No active debug session")?;
+
+    // Child counts come from the variable entry in its parent container
+    let response = session
+        .send_request(
+            "variables",
+            serde_json::json!({ "variablesReference": variables_reference }),
+        )
+        .await?;
+    let variable = response["body"]["variables"]
+        .as_array()
+        .and_then(|vars| vars.iter().find(|v| v["name"].as_str() == Some(name.as_str())))
+        .ok_or_else(|| format!("Variable '{}' not found", name))?;
+    let indexed_variables = variable["indexedVariables"].as_u64().map(|n| n as u32);
+    let named_variables = variable["namedVariables"].as_u64().map(|n| n as u32);
+    // Children such as `[0]` or an attribute only make sense as an expression
+    // through the path the adapter gives in evaluateName
+    let expression = variable["evaluateName"]
+        .as_str()
+        .unwrap_or(&name)
+        .to_string();
+
+    // The "clipboard" context makes debugpy return the full repr() instead of
+    // the truncated preview used in the variables view
+    let mut arguments = serde_json::json!({
+        "expression": expression,
+        "context": "clipboard",
+    });
+    if let Some(frame_id) = frame_id {
+        arguments["frameId"] = serde_json::json!(frame_id);
+    }
+    let response = session.send_request("evaluate", arguments).await?;
+
+    if response["success"].as_bool() == Some(false) {
+        return Err(response["message"]
+            .as_str()
+            .unwrap_or("Failed to evaluate variable")
+            .to_string());
+    }
+
+    Ok(VariableRepr {
+        name,
+        repr: response["body"]["result"].as_str().unwrap_or("").to_string(),
+        indexed_variables,
+        named_variables,
+    })
+}
+
+// What the debug adapter said it supports when the session started. Flags it
+// left out are false, per the DAP spec.
+#[tauri::command]
+pub async fn get_debug_capabilities(
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<DebugCapabilities, String> {
+    let manager = debug_manager.lock().await;
+    let session = manager.as_ref().ok_or("No active debug session")?;
+    let capabilities = &session.capabilities;
+    let flag = |name: &str| capabilities[name].as_bool().unwrap_or(false);
+
+    let exception_breakpoint_filters = capabilities["exceptionBreakpointFilters"]
+        .as_array()
+        .map(|filters| {
+            filters
+                .iter()
+                .map(|filter| ExceptionBreakpointFilter {
+                    filter: filter["filter"].as_str().unwrap_or("").to_string(),
+                    label: filter["label"].as_str().unwrap_or("").to_string(),
+                    default: filter["default"].as_bool().unwrap_or(false),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(DebugCapabilities {
+        supports_conditional_breakpoints: flag("supportsConditionalBreakpoints"),
+        supports_hit_conditional_breakpoints: flag("supportsHitConditionalBreakpoints"),
+        supports_log_points: flag("supportsLogPoints"),
+        supports_function_breakpoints: flag("supportsFunctionBreakpoints"),
+        supports_data_breakpoints: flag("supportsDataBreakpoints"),
+        supports_step_back: flag("supportsStepBack"),
+        supports_restart_frame: flag("supportsRestartFrame"),
+        supports_goto_targets_request: flag("supportsGotoTargetsRequest"),
+        supports_step_in_targets_request: flag("supportsStepInTargetsRequest"),
+        supports_set_variable: flag("supportsSetVariable"),
+        supports_set_expression: flag("supportsSetExpression"),
+        supports_completions_request: flag("supportsCompletionsRequest"),
+        supports_evaluate_for_hovers: flag("supportsEvaluateForHovers"),
+        supports_clipboard_context: flag("supportsClipboardContext"),
+        supports_exception_info_request: flag("supportsExceptionInfoRequest"),
+        supports_terminate_request: flag("supportsTerminateRequest"),
+        supports_restart_request: flag("supportsRestartRequest"),
+        exception_breakpoint_filters,
+    })
+}
+
+// Evaluate every watch expression against one frame under a single lock
+#[tauri::command]
+pub async fn evaluate_watches(
+    expressions: Vec<String>,
+    frame_id: u32,
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<Vec<WatchResult>, String> {
+    let mut manager = debug_manager.lock().await;
+    let session = manager.as_mut().ok_or("No active debug session")?;
+
+    let mut results = Vec::with_capacity(expressions.len());
+    for expression in expressions {
+        if expression.trim().is_empty() {
+            results.push(WatchResult {
+                expression,
+                result: None,
+                error: Some("Empty expression".to_string()),
+            });
+            continue;
+        }
+
+        let response = session
+            .send_request(
+                "evaluate",
+                serde_json::json!({
+                    "expression": expression,
+                    "frameId": frame_id,
+                    "context": "watch",
+                }),
+            )
+            .await?;
+
+        let watch = if response["success"].as_bool() == Some(false) {
+            WatchResult {
+                expression,
+                result: None,
+                error: Some(
+                    response["message"]
+                        .as_str()
+                        .unwrap_or("Failed to evaluate expression")
+                        .to_string(),
+                ),
+            }
+        } else {
+            WatchResult {
+                expression,
+                result: Some(
+                    response["body"]["result"]
+                        .as_str()
+                        .unwrap_or("")
+                        .to_string(),
+                ),
+                error: None,
+            }
+        };
+        results.push(watch);
+    }
+
+    Ok(results)
+}
+
+// Break when a variable changes. The adapter decides whether the variable
+// can be watched at all; debugpy, for one, may not support this.
+#[tauri::command]
+pub async fn set_data_breakpoint(
+    variables_reference: u32,
+    name: String,
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<DataBreakpoint, String> {
+    let mut manager = debug_manager.lock().await;
+    let session = manager.as_mut().ok_or("No active debug session")?;
+    if session.capabilities["supportsDataBreakpoints"].as_bool() != Some(true) {
+        return Err("The debugger does not support data breakpoints".to_string());
+    }
+
+    let info = session
+        .send_request(
+            "dataBreakpointInfo",
+            serde_json::json!({ "variablesReference": variables_reference, "name": name }),
+        )
+        .await?;
+    if info["success"].as_bool() == Some(false) {
+        return Err(format!(
+            "The debugger does not support data breakpoints: {}",
+            info["message"].as_str().unwrap_or("request failed")
+        ));
+    }
+
+    let description = info["body"]["description"]
+        .as_str()
+        .unwrap_or("")
+        .to_string();
+    let data_id = match info["body"]["dataId"].as_str() {
+        Some(data_id) => data_id.to_string(),
+        None => {
+            return Err(format!(
+                "Cannot break on changes to '{}': {}",
+                name,
+                if description.is_empty() {
+                    "not supported for this variable"
+                } else {
+                    description.as_str()
+                }
+            ))
+        }
+    };
+
+    session
+        .data_breakpoints
+        .retain(|bp| bp["dataId"].as_str() != Some(data_id.as_str()));
+    session
+        .data_breakpoints
+        .push(serde_json::json!({ "dataId": data_id }));
+
+    let response = session
+        .send_request(
+            "setDataBreakpoints",
+            serde_json::json!({ "breakpoints": session.data_breakpoints }),
+        )
+        .await?;
+    if response["success"].as_bool() == Some(false) {
+        session.data_breakpoints.pop();
+        return Err(format!(
+            "Failed to set data breakpoint: {}",
+            response["message"].as_str().unwrap_or("request failed")
+        ));
+    }
+
+    // Breakpoints come back in the order they were sent
+    let breakpoint = response["body"]["breakpoints"]
+        .as_array()
+        .and_then(|breakpoints| breakpoints.last());
+    Ok(DataBreakpoint {
+        data_id,
+        description,
+        verified: breakpoint
+            .and_then(|bp| bp["verified"].as_bool())
+            .unwrap_or(false),
+        message: breakpoint
+            .and_then(|bp| bp["message"].as_str())
+            .map(String::from),
+    })
+}
+
+// Undo Python's repr() of a str: debugpy returns evaluation results as reprs,
+// so a pformat() result comes back quoted with escaped newlines
+fn unquote_python_str(repr: &str) -> Option<String> {
+    let quote = repr.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    let inner = repr.strip_prefix(quote)?.strip_suffix(quote)?;
+
+    let mut value = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        let escaped = chars.next()?;
+        let hex_digits = match escaped {
+            'x' => 2,
+            'u' => 4,
+            'U' => 8,
+            'n' => {
+                value.push('\n');
+                continue;
+            }
+            't' => {
+                value.push('\t');
+                continue;
+            }
+            'r' => {
+                value.push('\r');
+                continue;
+            }
+            other => {
+                value.push(other);
+                continue;
+            }
+        };
+        let code: String = chars.by_ref().take(hex_digits).collect();
+        value.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+    }
+    Some(value)
+}
+
+// Full pretty-printed value of a variable, for values the variables view
+// truncates. The variable is looked up in its container so children are
+// evaluated through the expression debugpy gives for them.
+#[tauri::command]
+pub async fn inspect_variable(
+    variables_reference: u32,
+    name: String,
+    frame_id: u32,
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<VariableInspection, String> {
+    let mut manager = debug_manager.lock().await;
+    let session = manager.as_mut().ok_or("No active debug session")?;
+
+    let response = session
+        .send_request(
+            "variables",
+            serde_json::json!({ "variablesReference": variables_reference }),
+        )
+        .await?;
+    let expression = response["body"]["variables"]
+        .as_array()
+        .and_then(|vars| {
+            vars.iter()
+                .find(|v| v["name"].as_str() == Some(name.as_str()))
+        })
+        .and_then(|v| v["evaluateName"].as_str())
+        .unwrap_or(&name)
+        .to_string();
+
+    let response = session
+        .send_request(
+            "evaluate",
+            serde_json::json!({
+                "expression": format!("__import__('pprint').pformat({})", expression),
+                "frameId": frame_id,
+                "context": "clipboard",
+            }),
+        )
+        .await?;
+    if response["success"].as_bool() == Some(false) {
+        return Err(response["message"]
+            .as_str()
+            .unwrap_or("Failed to evaluate variable")
+            .to_string());
+    }
+
+    let result = response["body"]["result"].as_str().unwrap_or("");
+    Ok(VariableInspection {
+        name,
+        expression,
+        value: unquote_python_str(result).unwrap_or_else(|| result.to_string()),
+        language: "python".to_string(),
+    })
+}
+
 #[tauri::command]
 pub async fn stop_debug_session(
     debug_manager: State<'_, DebugSessionManager>,
@@ -576,6 +1217,145 @@ pub async fn stop_debug_session(
     Ok(())
 }
 
+// Limits for `export_debug_state`; self-referencing objects would otherwise
+// expand forever
+const EXPORT_MAX_DEPTH: usize = 4;
+const EXPORT_MAX_NODES: usize = 2000;
+
+// Groups debugpy adds to every object, which only bloat a snapshot
+const EXPORT_SKIPPED_GROUPS: &[&str] = &["special variables", "function variables"];
+
+// Children of a variables reference as a JSON object of
+// `name -> { type, value, children? }`
+fn export_variables<'a>(
+    session: &'a mut DebugSession,
+    variables_reference: u32,
+    depth: usize,
+    remaining: &'a mut usize,
+    truncated: &'a mut bool,
+) -> std::pin::Pin<
+    Box<dyn std::future::Future<Output = Result<serde_json::Value, String>> + Send + 'a>,
+> {
+    Box::pin(async move {
+        let response = session
+            .send_request(
+                "variables",
+                serde_json::json!({ "variablesReference": variables_reference }),
+            )
+            .await?;
+        let variables = response["body"]["variables"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+
+        let mut object = serde_json::Map::new();
+        for variable in variables {
+            let name = variable["name"].as_str().unwrap_or("").to_string();
+            if EXPORT_SKIPPED_GROUPS.contains(&name.as_str()) {
+                continue;
+            }
+            if *remaining == 0 {
+                *truncated = true;
+                break;
+            }
+            *remaining -= 1;
+
+            let mut node = serde_json::json!({
+                "type": variable["type"].as_str().unwrap_or(""),
+                "value": variable["value"].as_str().unwrap_or(""),
+            });
+            let child_reference = variable["variablesReference"].as_u64().unwrap_or(0) as u32;
+            if child_reference > 0 {
+                if depth + 1 < EXPORT_MAX_DEPTH {
+                    node["children"] =
+                        export_variables(session, child_reference, depth + 1, remaining, truncated)
+                            .await?;
+                } else {
+                    *truncated = true;
+                }
+            }
+            object.insert(name, node);
+        }
+
+        Ok(serde_json::Value::Object(object))
+    })
+}
+
+// Snapshot every scope of a stopped frame as nested JSON, e.g. to save and
+// compare program state between runs
+#[tauri::command]
+pub async fn export_debug_state(
+    thread_id: u32,
+    frame_id: u32,
+    debug_manager: State<'_, DebugSessionManager>,
+) -> Result<serde_json::Value, String> {
+    let mut manager = debug_manager.lock().await;
+    let session = manager.as_mut().ok_or("No active debug session")?;
+
+    let response = session
+        .send_request("scopes", serde_json::json!({ "frameId": frame_id }))
+        .await?;
+    let scopes = response["body"]["scopes"]
+        .as_array()
+        .cloned()
+        .ok_or("Invalid scopes response")?;
+
+    let mut remaining = EXPORT_MAX_NODES;
+    let mut truncated = false;
+    let mut exported = serde_json::Map::new();
+    for scope in scopes {
+        let name = scope["name"].as_str().unwrap_or("").to_string();
+        let reference = scope["variablesReference"].as_u64().unwrap_or(0) as u32;
+        let variables =
+            export_variables(session, reference, 0, &mut remaining, &mut truncated).await?;
+        exported.insert(name, variables);
+    }
+
+    Ok(serde_json::json!({
+        "thread_id": thread_id,
+        "frame_id": frame_id,
+        "exported_at": chrono::Local::now().to_rfc3339(),
+        "truncated": truncated,
+        "scopes": exported,
+    }))
+}
+
+// Check that breakpoints sit on executable statements. debugpy silently moves
+// or drops breakpoints on blank lines and comments, so the frontend snaps them
+// to `nearest` instead. Ties go to the following line, where execution
+// continues.
+#[tauri::command]
+pub async fn validate_breakpoint_lines(
+    file_path: String,
+    lines: Vec<u32>,
+) -> Result<Vec<BreakpointLineCheck>, String> {
+    let source =
+        std::fs::read_to_string(&file_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let executable = executable_lines(&source);
+
+    Ok(lines
+        .into_iter()
+        .map(|line| {
+            if executable.binary_search(&line).is_ok() {
+                return BreakpointLineCheck {
+                    line,
+                    valid: true,
+                    nearest: Some(line),
+                };
+            }
+            let nearest = executable
+                .iter()
+                .copied()
+                .min_by_key(|&candidate| (candidate.abs_diff(line), candidate < line));
+            BreakpointLineCheck {
+                line,
+                valid: false,
+                nearest,
+            }
+        })
+        .collect())
+}
+
 // Event loop to listen for debug events
 async fn debug_event_loop(manager: DebugSessionManager, window: Window) {
     println!("[DEBUG] Event loop started");
@@ -596,7 +1376,7 @@ async fn debug_event_loop(manager: DebugSessionManager, window: Window) {
         let event_result = {
             let mut mgr = manager.lock().await;
             if let Some(session) = mgr.as_mut() {
-                session.read_response().await
+                session.next_message().await
             } else {
                 break;
             }
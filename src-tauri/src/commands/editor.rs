@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::file::write_file_atomic;
+
+// Toggle `# ` comments on lines `start_line..=end_line` (1-based). Lines are
+// uncommented when every non-blank line in the range is already a comment,
+// otherwise commented at the indentation shared by the whole block.
+#[tauri::command]
+pub async fn toggle_line_comment(
+    content: String,
+    start_line: u32,
+    end_line: u32,
+) -> Result<String, String> {
+    if start_line == 0 || end_line < start_line {
+        return Err(format!("Invalid line range {}-{}", start_line, end_line));
+    }
+
+    // Keep each line's own ending so CRLF files round-trip unchanged
+    let mut lines: Vec<String> = content.split_inclusive('\n').map(String::from).collect();
+    let start = (start_line - 1) as usize;
+    let end = (end_line as usize).min(lines.len());
+    if start >= end {
+        return Ok(content);
+    }
+
+    let is_blank = |line: &str| line.trim().is_empty();
+    let indentation =
+        |line: &str| -> usize { line.len() - line.trim_start_matches([' ', '\t']).len() };
+
+    let block: Vec<usize> = (start..end).filter(|&i| !is_blank(&lines[i])).collect();
+    if block.is_empty() {
+        return Ok(content);
+    }
+
+    let all_commented = block
+        .iter()
+        .all(|&i| lines[i].trim_start_matches([' ', '\t']).starts_with('#'));
+
+    if all_commented {
+        for &i in &block {
+            let line = &mut lines[i];
+            let hash = indentation(line);
+            let marker_len = if line[hash + 1..].starts_with(' ') {
+                2
+            } else {
+                1
+            };
+            line.replace_range(hash..hash + marker_len, "");
+        }
+    } else {
+        // Longest leading whitespace shared by every line, so blocks mixing
+        // tabs and spaces still get a consistent column
+        let first = &lines[block[0]];
+        let mut common = indentation(first);
+        for &i in &block[1..] {
+            let line = &lines[i];
+            common = first.as_bytes()[..common]
+                .iter()
+                .zip(line.as_bytes()[..indentation(line)].iter())
+                .take_while(|(a, b)| a == b)
+                .count();
+        }
+
+        for &i in &block {
+            lines[i].insert_str(common, "# ");
+        }
+    }
+
+    Ok(lines.concat())
+}
+
+// Lines looked at when guessing the indentation; the style is settled well
+// before this in any real file
+const INDENT_SAMPLE_LINES: usize = 2000;
+const DEFAULT_INDENT_SIZE: u32 = 4;
+
+#[derive(Serialize, Deserialize)]
+pub struct IndentationInfo {
+    pub uses_tabs: bool,
+    // Width of one indentation level in spaces; the default when using tabs
+    pub indent_size: u32,
+    // 1-based
+    pub trailing_whitespace_lines: Vec<u32>,
+}
+
+// Guess a file's indentation style from its lines: tabs or spaces by the
+// majority of indented lines, and the size by the most common step in
+// leading spaces between consecutive lines
+#[tauri::command]
+pub async fn detect_indentation(path: String) -> Result<IndentationInfo, String> {
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let mut tab_lines = 0;
+    let mut space_lines = 0;
+    let mut steps: HashMap<usize, usize> = HashMap::new();
+    let mut previous_spaces = 0;
+    let mut trailing_whitespace_lines = Vec::new();
+
+    for (index, line) in content.split('\n').enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.ends_with([' ', '\t']) {
+            trailing_whitespace_lines.push(index as u32 + 1);
+        }
+        if index >= INDENT_SAMPLE_LINES || line.trim().is_empty() {
+            continue;
+        }
+
+        if line.starts_with('\t') {
+            tab_lines += 1;
+            continue;
+        }
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+        if spaces > 0 {
+            space_lines += 1;
+        }
+        // Mixed leading whitespace says nothing about the step size
+        if line[spaces..].starts_with('\t') {
+            continue;
+        }
+        if spaces > previous_spaces {
+            *steps.entry(spaces - previous_spaces).or_default() += 1;
+        }
+        previous_spaces = spaces;
+    }
+
+    let uses_tabs = tab_lines > space_lines;
+    let indent_size = if uses_tabs {
+        DEFAULT_INDENT_SIZE
+    } else {
+        // Ties go to the smaller step
+        steps
+            .into_iter()
+            .filter(|(step, _)| (2..=8).contains(step))
+            .max_by(|(a_step, a_count), (b_step, b_count)| {
+                a_count.cmp(b_count).then(b_step.cmp(a_step))
+            })
+            .map(|(step, _)| step as u32)
+            .unwrap_or(DEFAULT_INDENT_SIZE)
+    };
+
+    Ok(IndentationInfo {
+        uses_tabs,
+        indent_size,
+        trailing_whitespace_lines,
+    })
+}
+
+// Clean up a file's whitespace in place: trailing whitespace, a single final
+// newline, and line endings ("lf", "crlf", or "keep" to leave them alone).
+// Returns how many lines changed; the file is only rewritten if any did.
+#[tauri::command]
+pub async fn normalize_file(
+    path: String,
+    trim_trailing: bool,
+    ensure_final_newline: bool,
+    line_ending: String,
+) -> Result<u32, String> {
+    let target_ending = match line_ending.to_lowercase().as_str() {
+        "lf" | "\n" => Some("\n"),
+        "crlf" | "\r\n" => Some("\r\n"),
+        "" | "keep" => None,
+        other => return Err(format!("Unknown line ending: {}", other)),
+    };
+
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let original: Vec<(&str, &str)> = content
+        .split_inclusive('\n')
+        .map(|line| match line.strip_suffix("\r\n") {
+            Some(body) => (body, "\r\n"),
+            None => match line.strip_suffix('\n') {
+                Some(body) => (body, "\n"),
+                None => (line, ""),
+            },
+        })
+        .collect();
+
+    let mut normalized: Vec<(&str, &str)> = original
+        .iter()
+        .map(|&(body, ending)| {
+            let body = if trim_trailing {
+                body.trim_end_matches([' ', '\t'])
+            } else {
+                body
+            };
+            let ending = match target_ending {
+                Some(target) if !ending.is_empty() => target,
+                _ => ending,
+            };
+            (body, ending)
+        })
+        .collect();
+
+    if ensure_final_newline {
+        while normalized.len() > 1 && normalized[normalized.len() - 1].0.is_empty() {
+            normalized.pop();
+        }
+        // A file that is nothing but blank lines ends up empty
+        if normalized.len() == 1 && normalized[0].0.is_empty() {
+            normalized.pop();
+        }
+        if let Some(last) = normalized.last_mut() {
+            if last.1.is_empty() {
+                let default_ending = original.first().map(|line| line.1).unwrap_or("");
+                last.1 = match target_ending {
+                    Some(target) => target,
+                    None if !default_ending.is_empty() => default_ending,
+                    None => "\n",
+                };
+            }
+        }
+    }
+
+    let changed = original
+        .iter()
+        .enumerate()
+        .filter(|(i, line)| normalized.get(*i) != Some(*line))
+        .count();
+    if changed > 0 {
+        let updated: String = normalized
+            .iter()
+            .flat_map(|(body, ending)| [*body, *ending])
+            .collect();
+        write_file_atomic(Path::new(&path), updated.as_bytes())
+            .map_err(|e| format!("Failed to write file: {}", e))?;
+    }
+    Ok(changed as u32)
+}
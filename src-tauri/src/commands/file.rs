@@ -1,7 +1,24 @@
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tauri::Manager;
+use toml_edit::visit_mut::{self, VisitMut};
+use toml_edit::{Document, Item, KeyMut, Table, Value};
+
+// Directories that never contain project sources worth scanning
+const IGNORED_DIRS: &[&str] = &[
+    ".venv",
+    ".venvs",
+    ".git",
+    "__pycache__",
+    "node_modules",
+    ".mypy_cache",
+    ".ruff_cache",
+    ".pytest_cache",
+];
 
 #[derive(Serialize, Deserialize)]
 pub struct FileItem {
@@ -11,6 +28,28 @@ pub struct FileItem {
     pub size: Option<u64>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct FilePeek {
+    pub is_binary: bool,
+    pub preview: String,
+    pub detected_type: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConfigParseError {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConfigFormatResult {
+    pub format: String,
+    pub content: Option<String>,
+    pub error: Option<ConfigParseError>,
+    pub warning: Option<String>,
+}
+
 #[tauri::command]
 pub async fn read_file(path: String) -> Result<String, String> {
     fs::read_to_string(path).map_err(|e| e.to_string())
@@ -18,7 +57,54 @@ pub async fn read_file(path: String) -> Result<String, String> {
 
 #[tauri::command]
 pub async fn write_file(path: String, content: String) -> Result<(), String> {
-    fs::write(path, content).map_err(|e| e.to_string())
+    write_file_atomic(Path::new(&path), content.as_bytes()).map_err(|e| e.to_string())
+}
+
+static TMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+// Write to a temporary file next to the target and rename it into place, so a
+// crash mid-write never leaves a truncated file behind
+pub fn write_file_atomic(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("file");
+    // Concurrent saves of the same file each get their own temporary file
+    let unique = TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let tmp_path = dir.join(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        unique
+    ));
+    let mut tmp = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&tmp_path)?;
+
+    let result = (|| {
+        tmp.write_all(content)?;
+        tmp.sync_all()?;
+
+        // Keep the original file's permissions (e.g. executable scripts)
+        #[cfg(unix)]
+        {
+            if let Ok(metadata) = fs::metadata(path) {
+                fs::set_permissions(&tmp_path, metadata.permissions())?;
+            }
+        }
+
+        fs::rename(&tmp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
 }
 
 #[tauri::command]
@@ -86,6 +172,73 @@ pub async fn file_exists(path: String) -> bool {
     Path::new(&path).exists()
 }
 
+// Free bytes on the filesystem holding `path`. The path doesn't have to exist
+// yet (e.g. a venv about to be created); its nearest existing ancestor is used.
+#[tauri::command]
+pub async fn get_available_disk_space(path: String) -> Result<u64, String> {
+    let existing = Path::new(&path)
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .ok_or_else(|| format!("Path not found: {}", path))?;
+    let resolved = existing
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", path, e))?;
+    // Mount points are listed without the verbatim prefix canonicalize adds
+    #[cfg(target_os = "windows")]
+    let resolved = match resolved.to_string_lossy().strip_prefix(r"\\?\") {
+        Some(stripped) => PathBuf::from(stripped),
+        None => resolved.clone(),
+    };
+
+    // The deepest mount point containing the path is the one it lives on
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| resolved.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().components().count())
+        .map(|disk| disk.available_space())
+        .ok_or_else(|| format!("Could not find the disk containing {}", path))
+}
+
+// Upper bound on a peek, however many bytes the caller asks for
+const MAX_PEEK_BYTES: usize = 1024 * 1024;
+
+// Look at the start of a file to decide whether it can be opened as text,
+// without reading the whole thing
+#[tauri::command]
+pub async fn peek_file(path: String, bytes: usize) -> Result<FilePeek, String> {
+    let file = fs::File::open(&path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut head = Vec::new();
+    file.take(bytes.min(MAX_PEEK_BYTES) as u64)
+        .read_to_end(&mut head)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let is_binary = head.contains(&0);
+    let detected_type = match infer::get(&head) {
+        Some(kind) => kind.mime_type().to_string(),
+        None if is_binary => "application/octet-stream".to_string(),
+        None => "text/plain".to_string(),
+    };
+
+    let preview = if is_binary {
+        String::new()
+    } else {
+        // The cut may land inside a multi-byte character
+        let complete = match std::str::from_utf8(&head) {
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            _ => head.len(),
+        };
+        String::from_utf8_lossy(&head[..complete]).to_string()
+    };
+
+    Ok(FilePeek {
+        is_binary,
+        preview,
+        detected_type,
+    })
+}
+
 #[tauri::command]
 pub async fn open_file_dialog(app_handle: tauri::AppHandle) -> Result<Option<String>, String> {
     use tauri_plugin_dialog::DialogExt;
@@ -149,3 +302,217 @@ pub async fn save_file_dialog(app_handle: tauri::AppHandle) -> Result<Option<Str
         Err(_) => Err("Dialog was cancelled or failed".to_string())
     }
 }
+
+// Recursively collect files with the given extension, skipping virtualenvs,
+// VCS metadata and tool caches
+pub fn find_files_with_extension(root: &Path, extension: &str) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    collect_files(root, extension, &mut files);
+    files
+}
+
+fn collect_files(dir: &Path, extension: &str, files: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            let name = entry.file_name();
+            if !IGNORED_DIRS.contains(&name.to_string_lossy().as_ref()) {
+                collect_files(&path, extension, files);
+            }
+        } else if path.extension().and_then(|e| e.to_str()) == Some(extension) {
+            files.push(path);
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn format_config_file(path: String) -> Result<ConfigFormatResult, String> {
+    let format = Path::new(&path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .ok_or("File has no extension")?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let (format, formatted, warning) = match format.as_str() {
+        "json" => ("json", format_json(&content), None),
+        "toml" => ("toml", format_toml(&content), None),
+        "yaml" | "yml" => {
+            let warning = content
+                .contains('#')
+                .then(|| "Comments are not preserved when formatting YAML".to_string());
+            ("yaml", format_yaml(&content), warning)
+        }
+        other => return Err(format!("Unsupported config format: .{}", other)),
+    };
+
+    Ok(match formatted {
+        Ok(formatted) => ConfigFormatResult {
+            format: format.to_string(),
+            content: Some(formatted),
+            error: None,
+            warning,
+        },
+        Err(error) => ConfigFormatResult {
+            format: format.to_string(),
+            content: None,
+            error: Some(error),
+            warning: None,
+        },
+    })
+}
+
+// A JSON value that keeps object keys in the order they were written, unlike
+// serde_json::Value
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum OrderedJson {
+    Null,
+    Bool(bool),
+    Number(serde_json::Number),
+    String(String),
+    Array(Vec<OrderedJson>),
+    Object(IndexMap<String, OrderedJson>),
+}
+
+fn format_json(content: &str) -> Result<String, ConfigParseError> {
+    let value: OrderedJson =
+        serde_json::from_str(content).map_err(|e| ConfigParseError {
+            message: e.to_string(),
+            line: Some(e.line()),
+            column: Some(e.column()),
+        })?;
+    let mut formatted = serde_json::to_string_pretty(&value).map_err(|e| ConfigParseError {
+        message: e.to_string(),
+        line: None,
+        column: None,
+    })?;
+    formatted.push('\n');
+    Ok(formatted)
+}
+
+fn format_yaml(content: &str) -> Result<String, ConfigParseError> {
+    let to_error = |e: serde_norway::Error| ConfigParseError {
+        line: e.location().map(|l| l.line()),
+        column: e.location().map(|l| l.column()),
+        message: e.to_string(),
+    };
+
+    // A file may hold several documents separated by `---`
+    let mut documents = Vec::new();
+    for document in serde_norway::Deserializer::from_str(content) {
+        let value = serde_norway::Value::deserialize(document).map_err(to_error)?;
+        documents.push(serde_norway::to_string(&value).map_err(to_error)?);
+    }
+    Ok(documents.join("---\n"))
+}
+
+// Only whitespace is normalized; comments, key order and the layout of
+// arrays and inline tables are left as written
+fn format_toml(content: &str) -> Result<String, ConfigParseError> {
+    let mut document: Document = content.parse().map_err(|e: toml_edit::TomlError| {
+        let (line, column) = match e.span() {
+            Some(span) => {
+                let (line, column) = line_column(content, span.start);
+                (Some(line), Some(column))
+            }
+            None => (None, None),
+        };
+        ConfigParseError {
+            message: e.message().to_string(),
+            line,
+            column,
+        }
+    })?;
+
+    TomlFormatter.visit_document_mut(&mut document);
+    let trailing = normalize_comment_lines(document.trailing().as_str().unwrap_or(""));
+    document.set_trailing(trailing);
+
+    let mut formatted = document.to_string().trim().to_string();
+    formatted.push('\n');
+    Ok(formatted)
+}
+
+struct TomlFormatter;
+
+impl VisitMut for TomlFormatter {
+    fn visit_table_mut(&mut self, node: &mut Table) {
+        let decor = node.decor_mut();
+        let prefix = normalize_comment_lines(raw_decor(decor.prefix()));
+        let suffix = normalize_trailing_comment(raw_decor(decor.suffix()));
+        decor.set_prefix(prefix);
+        decor.set_suffix(suffix);
+        visit_mut::visit_table_mut(self, node);
+    }
+
+    fn visit_table_like_kv_mut(&mut self, mut key: KeyMut<'_>, node: &mut Item) {
+        let is_value = node.is_value();
+        let decor = key.decor_mut();
+        let prefix = normalize_comment_lines(raw_decor(decor.prefix()));
+        decor.set_prefix(prefix);
+        decor.set_suffix(if is_value { " " } else { "" });
+
+        if let Item::Value(value) = node {
+            let decor = value.decor_mut();
+            let suffix = normalize_trailing_comment(raw_decor(decor.suffix()));
+            decor.set_prefix(" ");
+            decor.set_suffix(suffix);
+        }
+        visit_mut::visit_table_like_kv_mut(self, key, node);
+    }
+
+    // Keep arrays and inline tables exactly as the user laid them out
+    fn visit_value_mut(&mut self, _node: &mut Value) {}
+}
+
+fn raw_decor(raw: Option<&toml_edit::RawString>) -> &str {
+    raw.and_then(|r| r.as_str()).unwrap_or("")
+}
+
+// Decor before a key or header holds whole comment/blank lines followed by the
+// indentation; drop the indentation and collapse runs of blank lines
+fn normalize_comment_lines(raw: &str) -> String {
+    let mut lines: Vec<&str> = raw.split('\n').collect();
+    lines.pop();
+
+    let mut normalized = String::new();
+    let mut previous_blank = false;
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() && previous_blank {
+            continue;
+        }
+        previous_blank = line.is_empty();
+        normalized.push_str(line);
+        normalized.push('\n');
+    }
+    normalized
+}
+
+fn normalize_trailing_comment(raw: &str) -> String {
+    let comment = raw.trim();
+    if comment.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", comment)
+    }
+}
+
+// Convert a byte offset into a 1-based line and column
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(content.len());
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &content[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
@@ -0,0 +1,551 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+use super::templates::create_gitignore;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GitBranchInfo {
+    pub branch: String,
+    pub detached: bool,
+    pub ahead: u32,
+    pub behind: u32,
+    pub has_upstream: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FileDiffContent {
+    pub old_content: String,
+    pub new_content: String,
+    pub is_new: bool,
+    pub is_deleted: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BlameLine {
+    pub line: u32,
+    pub commit: String,
+    pub author: String,
+    // Unix timestamp of the authored date
+    pub timestamp: i64,
+    pub summary: String,
+    // The line has local changes that aren't committed yet
+    pub uncommitted: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FileBlame {
+    // False for files git doesn't track, which have no blame lines
+    pub tracked: bool,
+    pub lines: Vec<BlameLine>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GitCommit {
+    pub hash: String,
+    pub author: String,
+    // ISO 8601 author date
+    pub date: String,
+    pub message: String,
+}
+
+// Build a git command rooted at the project directory
+pub(crate) fn git_command(project_path: &str) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.current_dir(project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(target_os = "windows")]
+    {
+        cmd.creation_flags(0x08000000);
+    }
+    cmd
+}
+
+// Run git and return stdout, or stderr as the error
+pub(crate) fn run_git(project_path: &str, args: &[&str]) -> Result<String, String> {
+    let output = git_command(project_path)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+// Express `path` relative to the project so it can be used in `<rev>:./<path>`
+fn relative_git_path(project_path: &str, path: &str) -> String {
+    let relative = Path::new(path)
+        .strip_prefix(project_path)
+        .unwrap_or_else(|_| Path::new(path));
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+#[tauri::command]
+pub async fn read_gitignore(project_path: String) -> Result<Vec<String>, String> {
+    let gitignore_path = Path::new(&project_path).join(".gitignore");
+
+    if !gitignore_path.exists() {
+        return Ok(vec![]);
+    }
+
+    let content = fs::read_to_string(&gitignore_path)
+        .map_err(|e| format!("Failed to read .gitignore: {}", e))?;
+
+    Ok(content.lines().map(|line| line.to_string()).collect())
+}
+
+#[tauri::command]
+pub async fn add_gitignore_entry(project_path: String, pattern: String) -> Result<bool, String> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return Err("Pattern cannot be empty".to_string());
+    }
+
+    let gitignore_path = Path::new(&project_path).join(".gitignore");
+
+    // Start from the built-in template when the project has no .gitignore yet
+    let mut content = if gitignore_path.exists() {
+        fs::read_to_string(&gitignore_path)
+            .map_err(|e| format!("Failed to read .gitignore: {}", e))?
+    } else {
+        create_gitignore().to_string()
+    };
+
+    if content.lines().any(|line| line.trim() == pattern) {
+        // Still persist the template if the file didn't exist before
+        if !gitignore_path.exists() {
+            fs::write(&gitignore_path, content)
+                .map_err(|e| format!("Failed to create .gitignore: {}", e))?;
+        }
+        return Ok(false);
+    }
+
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(pattern);
+    content.push('\n');
+
+    fs::write(&gitignore_path, content)
+        .map_err(|e| format!("Failed to write .gitignore: {}", e))?;
+
+    Ok(true)
+}
+
+#[tauri::command]
+pub async fn get_ignored_files(
+    project_path: String,
+    paths: Vec<String>,
+) -> Result<Vec<String>, String> {
+    Ok(ignored_paths(&project_path, &paths))
+}
+
+// Return the subset of `paths` ignored by git, checking them all in a single
+// git invocation. Anything outside a git repo is reported as not ignored.
+pub(crate) fn ignored_paths(project_path: &str, paths: &[String]) -> Vec<String> {
+    if paths.is_empty() {
+        return vec![];
+    }
+
+    let mut cmd = git_command(project_path);
+    cmd.args(["check-ignore", "--stdin"]).stdin(Stdio::piped());
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("git not available: {}", e);
+            return vec![];
+        }
+    };
+
+    // Feed stdin from a separate thread so a full stdout pipe can't deadlock us
+    let mut stdin = match child.stdin.take() {
+        Some(stdin) => stdin,
+        None => return vec![],
+    };
+    let input = paths.join("\n") + "\n";
+    let writer = std::thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+
+    let output = child.wait_with_output();
+    let _ = writer.join();
+
+    // Exit code 1 means nothing is ignored, 128 means this isn't a git repo
+    match output {
+        Ok(output) if output.status.code() == Some(0) => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+#[tauri::command]
+pub async fn git_branch_info(project_path: String) -> Result<GitBranchInfo, String> {
+    let output = git_command(&project_path)
+        .args(["status", "--porcelain=v2", "--branch", "--untracked-files=no"])
+        .output()
+        .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let mut info = GitBranchInfo {
+        branch: String::new(),
+        detached: false,
+        ahead: 0,
+        behind: 0,
+        has_upstream: false,
+    };
+    let mut oid = String::new();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for line in stdout.lines() {
+        if let Some(value) = line.strip_prefix("# branch.oid ") {
+            oid = value.trim().to_string();
+        } else if let Some(value) = line.strip_prefix("# branch.head ") {
+            if value.trim() == "(detached)" {
+                info.detached = true;
+            } else {
+                info.branch = value.trim().to_string();
+            }
+        } else if line.starts_with("# branch.upstream ") {
+            info.has_upstream = true;
+        } else if let Some(value) = line.strip_prefix("# branch.ab ") {
+            // "+3 -1"
+            for part in value.split_whitespace() {
+                if let Some(ahead) = part.strip_prefix('+') {
+                    info.ahead = ahead.parse().unwrap_or(0);
+                } else if let Some(behind) = part.strip_prefix('-') {
+                    info.behind = behind.parse().unwrap_or(0);
+                }
+            }
+        }
+    }
+
+    // Show the short commit hash when HEAD isn't on a branch
+    if info.detached {
+        info.branch = oid.chars().take(7).collect();
+    }
+
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn git_stage_file(project_path: String, path: String) -> Result<(), String> {
+    run_git(&project_path, &["add", "--", &path]).map(|_| ())
+}
+
+#[tauri::command]
+pub async fn git_unstage_file(project_path: String, path: String) -> Result<(), String> {
+    run_git(&project_path, &["restore", "--staged", "--", &path]).map(|_| ())
+}
+
+#[tauri::command]
+pub async fn git_discard_changes(
+    project_path: String,
+    path: String,
+    confirm: bool,
+) -> Result<(), String> {
+    // Discarding throws away uncommitted work, so require explicit confirmation
+    if !confirm {
+        return Err("Discarding changes is irreversible and must be confirmed".to_string());
+    }
+    run_git(&project_path, &["checkout", "--", &path]).map(|_| ())
+}
+
+#[tauri::command]
+pub async fn git_file_diff_content(
+    project_path: String,
+    path: String,
+) -> Result<FileDiffContent, String> {
+    let relative = relative_git_path(&project_path, &path);
+
+    // A file missing from HEAD is newly added; any other failure (not a
+    // repository, no git) is a real error
+    let head_version = match run_git(&project_path, &["show", &format!("HEAD:./{}", relative)]) {
+        Ok(content) => Some(content),
+        Err(e) if e.contains("does not exist in") || e.contains("exists on disk, but not in") => {
+            None
+        }
+        Err(e) => return Err(e),
+    };
+
+    let working_path = Path::new(&project_path).join(&relative);
+    let working_version = if working_path.is_file() {
+        Some(
+            fs::read_to_string(&working_path)
+                .map_err(|e| format!("Failed to read {}: {}", relative, e))?,
+        )
+    } else {
+        None
+    };
+
+    if head_version.is_none() && working_version.is_none() {
+        return Err(format!("'{}' exists neither in HEAD nor in the working tree", relative));
+    }
+
+    Ok(FileDiffContent {
+        is_new: head_version.is_none(),
+        is_deleted: working_version.is_none(),
+        old_content: head_version.unwrap_or_default(),
+        new_content: working_version.unwrap_or_default(),
+    })
+}
+
+// Commit details from `git blame --porcelain`, which are only printed the
+// first time a commit appears
+#[derive(Default, Clone)]
+struct BlameCommit {
+    author: String,
+    timestamp: i64,
+    summary: String,
+}
+
+fn parse_blame_porcelain(output: &str) -> Vec<BlameLine> {
+    let mut commits: HashMap<String, BlameCommit> = HashMap::new();
+    let mut lines = Vec::new();
+    let mut current: Option<(String, u32)> = None;
+
+    for line in output.lines() {
+        if line.starts_with('\t') {
+            // The line's content ends each entry
+            if let Some((commit, line_number)) = current.take() {
+                let info = commits.get(&commit).cloned().unwrap_or_default();
+                lines.push(BlameLine {
+                    line: line_number,
+                    uncommitted: commit.bytes().all(|b| b == b'0'),
+                    commit,
+                    author: info.author,
+                    timestamp: info.timestamp,
+                    summary: info.summary,
+                });
+            }
+            continue;
+        }
+
+        let mut parts = line.splitn(2, ' ');
+        let key = parts.next().unwrap_or("");
+        let value = parts.next().unwrap_or("");
+
+        if key.len() == 40 && key.bytes().all(|b| b.is_ascii_hexdigit()) {
+            // "<commit> <original line> <final line> [<group size>]"
+            let final_line = value
+                .split_whitespace()
+                .nth(1)
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(0);
+            commits.entry(key.to_string()).or_default();
+            current = Some((key.to_string(), final_line));
+            continue;
+        }
+
+        if let Some(info) = current
+            .as_ref()
+            .and_then(|(commit, _)| commits.get_mut(commit))
+        {
+            match key {
+                "author" => info.author = value.to_string(),
+                "author-time" => info.timestamp = value.parse().unwrap_or(0),
+                "summary" => info.summary = value.to_string(),
+                _ => {}
+            }
+        }
+    }
+
+    lines
+}
+
+// Per-line blame for the editor gutter
+#[tauri::command]
+pub async fn git_blame(project_path: String, file_path: String) -> Result<FileBlame, String> {
+    let relative = relative_git_path(&project_path, &file_path);
+
+    // Untracked files have nothing to blame; anything else (e.g. not a
+    // repository) is a real error
+    if let Err(e) = run_git(&project_path, &["ls-files", "--error-unmatch", "--", &relative]) {
+        if e.contains("did not match") {
+            return Ok(FileBlame {
+                tracked: false,
+                lines: Vec::new(),
+            });
+        }
+        return Err(e);
+    }
+
+    let output = run_git(&project_path, &["blame", "--porcelain", "--", &relative])?;
+    Ok(FileBlame {
+        tracked: true,
+        lines: parse_blame_porcelain(&output),
+    })
+}
+
+const DEFAULT_HISTORY_LIMIT: u32 = 50;
+const MAX_HISTORY_LIMIT: u32 = 500;
+
+// Commits that touched a file, newest first, following renames
+#[tauri::command]
+pub async fn git_file_history(
+    project_path: String,
+    file_path: String,
+    limit: Option<u32>,
+) -> Result<Vec<GitCommit>, String> {
+    let relative = relative_git_path(&project_path, &file_path);
+    let limit = limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(1, MAX_HISTORY_LIMIT)
+        .to_string();
+
+    // Fields are split by the unit separator and records by the record
+    // separator, neither of which appears in commit subjects
+    let output = match run_git(
+        &project_path,
+        &[
+            "log",
+            "--follow",
+            "-n",
+            &limit,
+            "--format=%H%x1f%an%x1f%aI%x1f%s%x1e",
+            "--",
+            &relative,
+        ],
+    ) {
+        Ok(output) => output,
+        // A repository without commits has no history yet
+        Err(e) if e.contains("does not have any commits") => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    // An untracked file simply has no commits
+    Ok(output
+        .split('\u{1e}')
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').split('\u{1f}');
+            Some(GitCommit {
+                hash: fields.next().filter(|hash| !hash.is_empty())?.to_string(),
+                author: fields.next()?.to_string(),
+                date: fields.next()?.to_string(),
+                message: fields.next()?.to_string(),
+            })
+        })
+        .collect())
+}
+
+// Only plain commit hashes, so nothing can be read as an option or revision
+// expression
+fn validate_commit_hash(commit_hash: &str) -> Result<(), String> {
+    if (4..=40).contains(&commit_hash.len())
+        && commit_hash.bytes().all(|b| b.is_ascii_hexdigit())
+    {
+        Ok(())
+    } else {
+        Err(format!("Invalid commit hash: {}", commit_hash))
+    }
+}
+
+// A file's content at a given commit, without touching the working tree
+#[tauri::command]
+pub async fn git_restore_file_version(
+    project_path: String,
+    file_path: String,
+    commit_hash: String,
+) -> Result<String, String> {
+    validate_commit_hash(&commit_hash)?;
+    let relative = relative_git_path(&project_path, &file_path);
+    run_git(&project_path, &["show", &format!("{}:./{}", commit_hash, relative)])
+}
+
+// Overwrite the working copy with the file's content at a given commit. The
+// index is left alone, so the change shows up as an unstaged modification.
+#[tauri::command]
+pub async fn git_checkout_file_version(
+    project_path: String,
+    file_path: String,
+    commit_hash: String,
+    confirm: bool,
+) -> Result<(), String> {
+    // Overwriting the working copy loses uncommitted edits to the file
+    if !confirm {
+        return Err("Replacing the working copy is irreversible and must be confirmed".to_string());
+    }
+    validate_commit_hash(&commit_hash)?;
+    let relative = relative_git_path(&project_path, &file_path);
+    run_git(
+        &project_path,
+        &[
+            "restore",
+            &format!("--source={}", commit_hash),
+            "--worktree",
+            "--",
+            &relative,
+        ],
+    )
+    .map(|_| ())
+}
+
+// First line of hooks written by `setup_git_hooks`, so they can be replaced
+// without touching hooks the user wrote
+const PYRA_HOOK_MARKER: &str = "# Generated by Pyra";
+
+// Write a pre-commit hook running ruff and/or pytest. Returns the hook path.
+#[tauri::command]
+pub async fn setup_git_hooks(
+    project_path: String,
+    run_ruff: bool,
+    run_tests: bool,
+) -> Result<String, String> {
+    if !run_ruff && !run_tests {
+        return Err("Select at least one check for the pre-commit hook".to_string());
+    }
+
+    let git_dir = Path::new(&project_path).join(".git");
+    if !git_dir.is_dir() {
+        return Err("Not a git repository: .git directory not found".to_string());
+    }
+
+    let hooks_dir = git_dir.join("hooks");
+    fs::create_dir_all(&hooks_dir)
+        .map_err(|e| format!("Failed to create hooks directory: {}", e))?;
+    let hook_path = hooks_dir.join("pre-commit");
+
+    if let Ok(existing) = fs::read_to_string(&hook_path) {
+        if !existing.contains(PYRA_HOOK_MARKER) {
+            return Err(format!(
+                "A pre-commit hook already exists at {}; remove it first to replace it",
+                hook_path.display()
+            ));
+        }
+    }
+
+    let mut script = format!("#!/bin/sh\n{}\nset -e\n", PYRA_HOOK_MARKER);
+    if run_ruff {
+        script.push_str("\necho \"Running ruff...\"\nuv run ruff check .\n");
+    }
+    if run_tests {
+        // pytest exits with 5 when there are no tests yet, which is not a failure
+        script.push_str("\necho \"Running tests...\"\nuv run pytest || [ $? -eq 5 ]\n");
+    }
+    fs::write(&hook_path, script).map_err(|e| format!("Failed to write hook: {}", e))?;
+
+    // Git ignores hooks that aren't executable
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&hook_path, fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Failed to make hook executable: {}", e))?;
+    }
+
+    Ok(hook_path.to_string_lossy().to_string())
+}
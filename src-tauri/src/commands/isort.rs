@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use std::process::{Output, Stdio};
+
+use super::project::uv_project_command;
+
+#[derive(Serialize, Deserialize)]
+pub struct IsortCheckResult {
+    pub sorted: bool,
+    pub diff: String,
+}
+
+fn run_isort(project_path: &str, args: &[&str]) -> Result<Output, String> {
+    let mut cmd = uv_project_command(project_path);
+    cmd.args(["run", "isort"])
+        .args(args)
+        .current_dir(project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    cmd.output()
+        .map_err(|e| format!("Failed to execute uv run isort: {}", e))
+}
+
+// uv reports a missing executable as "Failed to spawn: `isort`"
+fn isort_error(output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if stderr.contains("Failed to spawn") && stderr.contains("isort") {
+        "isort is not installed in this project. Add it with `uv add --dev isort`.".to_string()
+    } else {
+        stderr.to_string()
+    }
+}
+
+#[tauri::command]
+pub async fn isort_file(project_path: String, file_path: String) -> Result<String, String> {
+    let output = run_isort(&project_path, &[&file_path])?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(isort_error(&output))
+    }
+}
+
+#[tauri::command]
+pub async fn isort_check(
+    project_path: String,
+    file_path: String,
+) -> Result<IsortCheckResult, String> {
+    let output = run_isort(&project_path, &["--check-only", "--diff", &file_path])?;
+
+    // Exit code 1 with "incorrectly sorted" means the file would change
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let unsorted = output.status.code() == Some(1) && stderr.contains("incorrectly sorted");
+    if !output.status.success() && !unsorted {
+        return Err(isort_error(&output));
+    }
+
+    Ok(IsortCheckResult {
+        sorted: !unsorted,
+        diff: String::from_utf8_lossy(&output.stdout).to_string(),
+    })
+}
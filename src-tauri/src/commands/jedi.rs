@@ -0,0 +1,325 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{Emitter, State, Window};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+
+use super::project::uv_project_command;
+
+// Per project, whether jedi is known to be importable. The inner lock is held
+// while checking and installing, so concurrent requests install it only once.
+pub type JediCache = Arc<Mutex<HashMap<String, Arc<Mutex<bool>>>>>;
+
+pub fn create_jedi_cache() -> JediCache {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+const COMPLETION_TIMEOUT_SECS: u64 = 10;
+// Hover fires on mouse movement, so give up quickly rather than queue up work
+const HOVER_TIMEOUT_SECS: u64 = 5;
+
+#[derive(Serialize, Deserialize)]
+pub struct Completion {
+    pub label: String,
+    pub kind: String,
+    pub detail: String,
+}
+
+// Each script reads {source, path, project, line, column} as JSON on stdin and
+// prints a single JSON document. Lines are 1-based, columns 0-based (jedi's
+// convention).
+const COMPLETIONS_SCRIPT: &str = r#"
+import json, sys
+import jedi
+
+req = json.load(sys.stdin)
+script = jedi.Script(req["source"], path=req["path"], project=jedi.Project(req["project"]))
+print(json.dumps([
+    {"label": c.name, "kind": c.type, "detail": c.description}
+    for c in script.complete(req["line"], req["column"])
+]))
+"#;
+
+#[derive(Serialize, Deserialize)]
+pub struct HoverInfo {
+    pub signature: String,
+    pub docstring: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+// Prefer the inferred value (what a variable holds), falling back to whatever
+// the name itself refers to
+const HOVER_SCRIPT: &str = r#"
+import json, sys
+import jedi
+
+req = json.load(sys.stdin)
+script = jedi.Script(req["source"], path=req["path"], project=jedi.Project(req["project"]))
+names = script.infer(req["line"], req["column"]) or script.help(req["line"], req["column"])
+if not names:
+    print("null")
+else:
+    name = names[0]
+    signatures = name.get_signatures()
+    print(json.dumps({
+        "signature": signatures[0].to_string() if signatures else name.description,
+        "docstring": name.docstring(raw=True),
+        "type": name.type,
+    }))
+"#;
+
+#[derive(Serialize, Deserialize)]
+pub struct Location {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DefinitionResult {
+    pub location: Option<Location>,
+    // Why there is nowhere to jump to, e.g. a built-in or C extension
+    pub reason: Option<String>,
+}
+
+const GOTO_SCRIPT: &str = r#"
+import json, sys
+import jedi
+
+req = json.load(sys.stdin)
+script = jedi.Script(req["source"], path=req["path"], project=jedi.Project(req["project"]))
+names = script.goto(req["line"], req["column"], follow_imports=True)
+if not names:
+    result = {"location": None, "reason": "No definition found"}
+else:
+    name = names[0]
+    path = name.module_path
+    if name.in_builtin_module() or path is None:
+        reason = "'%s' is built into Python and has no source" % name.name
+        result = {"location": None, "reason": reason}
+    elif path.suffix in (".so", ".pyd") or name.line is None:
+        reason = "'%s' is defined in a compiled extension (%s)" % (name.name, path.name)
+        result = {"location": None, "reason": reason}
+    else:
+        location = {"file": str(path), "line": name.line, "column": name.column + 1}
+        result = {"location": location, "reason": None}
+print(json.dumps(result))
+"#;
+
+fn uv_command(project_path: &str) -> Command {
+    let mut cmd = Command::from(uv_project_command(project_path));
+    cmd.current_dir(project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    cmd
+}
+
+// Make sure jedi can be imported in the project's environment. The caller only
+// waits `timeout_secs`; a first-time install that takes longer keeps running in
+// the background and later requests pick up the result.
+async fn ensure_jedi(
+    project_path: &str,
+    timeout_secs: u64,
+    jedi_cache: &JediCache,
+) -> Result<(), String> {
+    let ready = Arc::clone(
+        jedi_cache
+            .lock()
+            .await
+            .entry(project_path.to_string())
+            .or_default(),
+    );
+    if ready.try_lock().map_or(false, |ready| *ready) {
+        return Ok(());
+    }
+
+    let project = project_path.to_string();
+    let setup = tokio::spawn(async move {
+        let mut ready = ready.lock().await;
+        if !*ready {
+            install_jedi(&project).await?;
+            *ready = true;
+        }
+        Ok(())
+    });
+    tokio::time::timeout(Duration::from_secs(timeout_secs), setup)
+        .await
+        .map_err(|_| "jedi is still being installed, try again shortly".to_string())?
+        .map_err(|e| format!("jedi setup failed: {}", e))?
+}
+
+// Install jedi into the venv (without touching pyproject.toml) if it cannot be
+// imported yet
+async fn install_jedi(project_path: &str) -> Result<(), String> {
+    let mut check = uv_command(project_path);
+    check.args(["run", "python", "-c", "import jedi"]);
+    let importable = check
+        .output()
+        .await
+        .map_err(|e| format!("Failed to execute uv: {}", e))?
+        .status
+        .success();
+    if importable {
+        return Ok(());
+    }
+
+    let mut install = uv_command(project_path);
+    install.args(["pip", "install", "jedi"]);
+    let output = install
+        .output()
+        .await
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "jedi is not installed and could not be installed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+// The editor buffer and cursor for a jedi script. `column` is 1-based like
+// the editor's and converted for jedi here.
+fn jedi_request(
+    project_path: &str,
+    file_path: &str,
+    line: u32,
+    column: u32,
+    source: &str,
+) -> serde_json::Value {
+    serde_json::json!({
+        "source": source,
+        "path": file_path,
+        "project": project_path,
+        "line": line,
+        "column": column.saturating_sub(1),
+    })
+}
+
+async fn run_jedi(
+    project_path: &str,
+    script: &str,
+    request: serde_json::Value,
+    timeout_secs: u64,
+    jedi_cache: &JediCache,
+) -> Result<serde_json::Value, String> {
+    ensure_jedi(project_path, timeout_secs, jedi_cache).await?;
+
+    let mut cmd = uv_command(project_path);
+    cmd.args(["run", "python", "-c", script])
+        .stdin(Stdio::piped());
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(request.to_string().as_bytes())
+            .await
+            .map_err(|e| format!("Failed to send source to jedi: {}", e))?;
+    }
+
+    // The child is killed on drop if it runs past the deadline
+    let output = tokio::time::timeout(Duration::from_secs(timeout_secs), child.wait_with_output())
+        .await
+        .map_err(|_| format!("jedi did not respond within {} seconds", timeout_secs))?
+        .map_err(|e| format!("Failed to run jedi: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "jedi failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let result = stdout.lines().last().unwrap_or("");
+    serde_json::from_str(result).map_err(|e| format!("Failed to parse jedi output: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_completions(
+    project_path: String,
+    file_path: String,
+    line: u32,
+    column: u32,
+    source: String,
+    jedi_cache: State<'_, JediCache>,
+) -> Result<Vec<Completion>, String> {
+    let request = jedi_request(&project_path, &file_path, line, column, &source);
+    let result = run_jedi(
+        &project_path,
+        COMPLETIONS_SCRIPT,
+        request,
+        COMPLETION_TIMEOUT_SECS,
+        &jedi_cache,
+    )
+    .await?;
+
+    serde_json::from_value(result).map_err(|e| format!("Failed to parse completions: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_hover_info(
+    project_path: String,
+    file_path: String,
+    line: u32,
+    column: u32,
+    source: String,
+    jedi_cache: State<'_, JediCache>,
+) -> Result<Option<HoverInfo>, String> {
+    let request = jedi_request(&project_path, &file_path, line, column, &source);
+    let result = run_jedi(
+        &project_path,
+        HOVER_SCRIPT,
+        request,
+        HOVER_TIMEOUT_SECS,
+        &jedi_cache,
+    )
+    .await?;
+
+    serde_json::from_value(result).map_err(|e| format!("Failed to parse hover info: {}", e))
+}
+
+#[tauri::command]
+pub async fn goto_definition(
+    project_path: String,
+    file_path: String,
+    line: u32,
+    column: u32,
+    source: String,
+    jedi_cache: State<'_, JediCache>,
+) -> Result<DefinitionResult, String> {
+    let request = jedi_request(&project_path, &file_path, line, column, &source);
+    let result = run_jedi(
+        &project_path,
+        GOTO_SCRIPT,
+        request,
+        COMPLETION_TIMEOUT_SECS,
+        &jedi_cache,
+    )
+    .await?;
+
+    serde_json::from_value(result).map_err(|e| format!("Failed to parse definition: {}", e))
+}
+
+// Forget what is known about the project's interpreter after its venv is
+// recreated or switched. The jedi check is the only state kept between
+// calls; the Python version and ruff config are read fresh every time.
+#[tauri::command]
+pub async fn reset_environment_cache(
+    window: Window,
+    project_path: String,
+    jedi_cache: State<'_, JediCache>,
+) -> Result<(), String> {
+    jedi_cache.lock().await.remove(&project_path);
+    let _ = window.emit("environment-reset", &project_path);
+    Ok(())
+}
@@ -4,3 +4,19 @@ pub mod python;
 pub mod ruff;
 pub mod templates;
 pub mod debug;
+pub mod git;
+pub mod testing;
+pub mod watch;
+pub mod mypy;
+pub mod search;
+pub mod symbols;
+pub mod editor;
+pub mod jedi;
+pub mod isort;
+pub mod syntax;
+pub mod process;
+pub mod settings;
+pub mod pty;
+pub mod workspace;
+pub mod notebook;
+pub mod scratch;
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::process::Stdio;
+
+use super::project::uv_project_command;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MypyDiagnostic {
+    pub line: u32,
+    pub column: u32,
+    pub severity: String,
+    pub message: String,
+    pub code: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MypyProjectResult {
+    pub diagnostics: HashMap<String, Vec<MypyDiagnostic>>,
+    pub summary: Option<String>,
+}
+
+#[tauri::command]
+pub async fn mypy_check_project(project_path: String) -> Result<MypyProjectResult, String> {
+    // Leave the incremental cache (.mypy_cache) enabled so re-runs stay fast
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "mypy", ".", "--show-column-numbers"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv run mypy: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Exit code 1 means type errors were found; anything else non-zero is a failure
+    if !output.status.success() && output.status.code() != Some(1) {
+        return Err(if stderr.trim().is_empty() {
+            stdout.to_string()
+        } else {
+            stderr.to_string()
+        });
+    }
+
+    let mut diagnostics: HashMap<String, Vec<MypyDiagnostic>> = HashMap::new();
+    let mut summary = None;
+
+    for line in stdout.lines() {
+        if line.starts_with("Found ") || line.starts_with("Success:") {
+            summary = Some(line.trim().to_string());
+            continue;
+        }
+
+        if let Some((file, diagnostic)) = parse_mypy_line(line) {
+            diagnostics.entry(file).or_default().push(diagnostic);
+        }
+    }
+
+    Ok(MypyProjectResult {
+        diagnostics,
+        summary,
+    })
+}
+
+// Parse "path/to/file.py:12:5: error: Message  [code]"
+fn parse_mypy_line(line: &str) -> Option<(String, MypyDiagnostic)> {
+    let (location, severity, rest) = ["error", "warning", "note"]
+        .iter()
+        .find_map(|severity| {
+            let marker = format!(": {}: ", severity);
+            line.find(&marker)
+                .map(|pos| (&line[..pos], *severity, &line[pos + marker.len()..]))
+        })?;
+
+    // Split from the right so Windows drive letters stay part of the path
+    let mut parts = location.rsplitn(3, ':');
+    let column = parts.next()?.trim().parse().ok()?;
+    let line_number = parts.next()?.trim().parse().ok()?;
+    let file = parts.next()?.to_string();
+
+    let (message, code) = match rest.rfind("  [") {
+        Some(pos) if rest.ends_with(']') => (
+            rest[..pos].to_string(),
+            Some(rest[pos + 3..rest.len() - 1].to_string()),
+        ),
+        _ => (rest.to_string(), None),
+    };
+
+    Some((
+        file,
+        MypyDiagnostic {
+            line: line_number,
+            column,
+            severity: severity.to_string(),
+            message,
+            code,
+        },
+    ))
+}
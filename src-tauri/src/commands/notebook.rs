@@ -0,0 +1,168 @@
+use std::fs;
+use std::path::Path;
+
+use super::file::write_file_atomic;
+
+// Cells are delimited jupytext-style ("percent" format): `# %%` starts a code
+// cell and `# %% [markdown]` a markdown cell whose lines are commented out.
+const CELL_MARKER: &str = "# %%";
+
+// `source` is either a single string or a list of lines
+fn cell_source(cell: &serde_json::Value) -> String {
+    match &cell["source"] {
+        serde_json::Value::String(source) => source.clone(),
+        serde_json::Value::Array(lines) => lines.iter().filter_map(|line| line.as_str()).collect(),
+        _ => String::new(),
+    }
+}
+
+fn comment_lines(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                "#".to_string()
+            } else {
+                format!("# {}", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn uncomment_line(line: &str) -> &str {
+    line.strip_prefix("# ")
+        .or_else(|| line.strip_prefix('#'))
+        .unwrap_or(line)
+}
+
+fn notebook_cell(cell_type: &str, lines: &[&str]) -> serde_json::Value {
+    // Blank lines around a cell are only there to separate it from the next
+    let start = lines.iter().position(|line| !line.trim().is_empty());
+    let end = lines.iter().rposition(|line| !line.trim().is_empty());
+    let lines = match (start, end) {
+        (Some(start), Some(end)) => &lines[start..=end],
+        _ => &[],
+    };
+
+    let source: Vec<String> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = if cell_type == "code" {
+                *line
+            } else {
+                uncomment_line(line)
+            };
+            if i + 1 < lines.len() {
+                format!("{}\n", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+
+    if cell_type == "code" {
+        serde_json::json!({
+            "cell_type": "code",
+            "execution_count": null,
+            "metadata": {},
+            "outputs": [],
+            "source": source,
+        })
+    } else {
+        serde_json::json!({
+            "cell_type": cell_type,
+            "metadata": {},
+            "source": source,
+        })
+    }
+}
+
+// Convert a notebook to a percent-format script. Outputs are dropped.
+#[tauri::command]
+pub async fn notebook_to_script(
+    notebook_path: String,
+    output_path: String,
+) -> Result<String, String> {
+    let content = fs::read_to_string(&notebook_path)
+        .map_err(|e| format!("Failed to read notebook: {}", e))?;
+    let notebook: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse notebook: {}", e))?;
+    let cells = notebook["cells"]
+        .as_array()
+        .ok_or("Notebook has no cells list")?;
+
+    let mut blocks = Vec::new();
+    for cell in cells {
+        let source = cell_source(cell);
+        let source = source.trim_end();
+        let block = match cell["cell_type"].as_str() {
+            Some("code") => format!("{}\n{}", CELL_MARKER, source),
+            Some("markdown") => format!("{} [markdown]\n{}", CELL_MARKER, comment_lines(source)),
+            Some("raw") => format!("{} [raw]\n{}", CELL_MARKER, comment_lines(source)),
+            _ => continue,
+        };
+        blocks.push(block.trim_end().to_string());
+    }
+
+    let script = format!("{}\n", blocks.join("\n\n"));
+    write_file_atomic(Path::new(&output_path), script.as_bytes())
+        .map_err(|e| format!("Failed to write script: {}", e))?;
+    Ok(output_path)
+}
+
+// Split a percent-format script into notebook cells. Code before the first
+// marker becomes a code cell of its own.
+#[tauri::command]
+pub async fn script_to_notebook(
+    script_path: String,
+    output_path: String,
+) -> Result<String, String> {
+    let content =
+        fs::read_to_string(&script_path).map_err(|e| format!("Failed to read script: {}", e))?;
+
+    let mut cells = Vec::new();
+    let mut cell_type = "code";
+    let mut lines: Vec<&str> = Vec::new();
+    for line in content.lines() {
+        if let Some(rest) = line.strip_prefix(CELL_MARKER) {
+            if !lines.iter().all(|line| line.trim().is_empty()) {
+                cells.push(notebook_cell(cell_type, &lines));
+            }
+            lines.clear();
+            // Anything else after the marker is a cell title
+            cell_type = if rest.contains("[markdown]") || rest.contains("[md]") {
+                "markdown"
+            } else if rest.contains("[raw]") {
+                "raw"
+            } else {
+                "code"
+            };
+            continue;
+        }
+        lines.push(line);
+    }
+    if !lines.iter().all(|line| line.trim().is_empty()) {
+        cells.push(notebook_cell(cell_type, &lines));
+    }
+
+    let notebook = serde_json::json!({
+        "cells": cells,
+        "metadata": {
+            "kernelspec": {
+                "display_name": "Python 3",
+                "language": "python",
+                "name": "python3",
+            },
+            "language_info": { "name": "python" },
+        },
+        "nbformat": 4,
+        "nbformat_minor": 4,
+    });
+    let json = serde_json::to_string_pretty(&notebook)
+        .map_err(|e| format!("Failed to serialize notebook: {}", e))?;
+    write_file_atomic(Path::new(&output_path), format!("{}\n", json).as_bytes())
+        .map_err(|e| format!("Failed to write notebook: {}", e))?;
+    Ok(output_path)
+}
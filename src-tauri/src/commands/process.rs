@@ -0,0 +1,165 @@
+use std::io::{self, Read};
+use std::process::{Child, Command, ExitStatus};
+
+// A script process together with everything it starts. `uv run` launches
+// python as a grandchild, so killing only the direct child leaves the script
+// running. On Unix the child leads its own process group; on Windows it is
+// placed in a Job Object.
+pub struct ProcessTree {
+    child: Child,
+    #[cfg(target_os = "windows")]
+    job: Option<job::Job>,
+}
+
+// Call on the command before spawning it as a `ProcessTree`
+pub fn new_process_group(cmd: &mut Command) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // setpgid is async-signal-safe, so it can run between fork and exec
+        unsafe {
+            cmd.pre_exec(|| {
+                if libc::setpgid(0, 0) == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            });
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = cmd;
+}
+
+impl ProcessTree {
+    pub fn new(child: Child) -> Self {
+        #[cfg(target_os = "windows")]
+        {
+            // Anything the child starts from here on joins the job too
+            let job = job::Job::new().filter(|job| job.assign(&child));
+            ProcessTree { child, job }
+        }
+        #[cfg(not(target_os = "windows"))]
+        ProcessTree { child }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.child.id()
+    }
+
+    // Kill the child and all of its descendants
+    pub fn kill(&mut self) -> io::Result<()> {
+        #[cfg(unix)]
+        {
+            // The group id is the child's pid, see `new_process_group`
+            let pgid = self.child.id() as libc::pid_t;
+            unsafe {
+                libc::kill(-pgid, libc::SIGKILL);
+            }
+        }
+        #[cfg(target_os = "windows")]
+        if let Some(job) = &self.job {
+            job.terminate();
+        }
+        self.child.kill()
+    }
+
+    pub fn wait(&mut self) -> io::Result<ExitStatus> {
+        self.child.wait()
+    }
+
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.child.try_wait()
+    }
+}
+
+// Read until EOF, passing on text as soon as it arrives. A character split
+// across two reads is held back until it is complete.
+pub fn read_utf8_chunks(mut reader: impl Read, mut on_chunk: impl FnMut(String)) {
+    let mut buffer = [0u8; 4096];
+    let mut pending: Vec<u8> = Vec::new();
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => read,
+        };
+        pending.extend_from_slice(&buffer[..read]);
+
+        let complete = match std::str::from_utf8(&pending) {
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            _ => pending.len(),
+        };
+        if complete == 0 {
+            continue;
+        }
+        on_chunk(String::from_utf8_lossy(&pending[..complete]).to_string());
+        pending.drain(..complete);
+    }
+
+    if !pending.is_empty() {
+        on_chunk(String::from_utf8_lossy(&pending).to_string());
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod job {
+    use std::os::windows::io::AsRawHandle;
+    use std::process::Child;
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+        SetInformationJobObject, TerminateJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    };
+
+    pub struct Job(HANDLE);
+
+    // The handle is only used through the owning ProcessTree, which lives
+    // behind the process manager's mutex
+    unsafe impl Send for Job {}
+
+    impl Job {
+        pub fn new() -> Option<Job> {
+            unsafe {
+                let handle = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+                if handle.is_null() {
+                    return None;
+                }
+                let job = Job(handle);
+
+                // Closing the last handle to the job also kills its processes,
+                // so nothing outlives Pyra
+                let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+                let ok = SetInformationJobObject(
+                    job.0,
+                    JobObjectExtendedLimitInformation,
+                    &info as *const _ as *const core::ffi::c_void,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                );
+                if ok == 0 {
+                    return None;
+                }
+                Some(job)
+            }
+        }
+
+        pub fn assign(&self, child: &Child) -> bool {
+            unsafe { AssignProcessToJobObject(self.0, child.as_raw_handle() as HANDLE) != 0 }
+        }
+
+        pub fn terminate(&self) {
+            unsafe {
+                TerminateJobObject(self.0, 1);
+            }
+        }
+    }
+
+    impl Drop for Job {
+        fn drop(&mut self) {
+            unsafe {
+                CloseHandle(self.0);
+            }
+        }
+    }
+}
@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::process::{Command, Stdio};
-#[cfg(target_os = "windows")]
-use std::os::windows::process::CommandExt;
 use tauri_plugin_dialog::DialogExt;
 use tauri::Manager;
 
+use super::file::write_file_atomic;
+use super::settings::uv_command;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProjectConfig {
     pub name: String,
@@ -15,6 +18,26 @@ pub struct ProjectConfig {
     pub dependencies: Vec<String>,
     pub created_at: String,
     pub last_opened: String,
+    // Named venv under .venvs/ used for run/debug; None means the default .venv
+    #[serde(default)]
+    pub active_venv: Option<String>,
+    // Named presets for the run button, see `RunConfig`
+    #[serde(default)]
+    pub run_configs: Vec<RunConfig>,
+}
+
+// A saved command line, e.g. "run server" -> `uvicorn main:app --reload`.
+// `working_dir` is relative to the project; None means the project root.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunConfig {
+    pub name: String,
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    #[serde(default)]
+    pub working_dir: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -42,6 +65,47 @@ pub struct BuildSystem {
     pub build_backend: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GeneratedReadme {
+    pub content: String,
+    pub path: String,
+    // Whether a README.md was already there
+    pub existed: bool,
+    pub written: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunSuggestion {
+    pub label: String,
+    pub command: String,
+    pub args: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RenameProjectResult {
+    pub project_path: String,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CursorPos {
+    pub line: u32,
+    pub column: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EditorSession {
+    pub open_files: Vec<String>,
+    pub active_file: Option<String>,
+    pub cursor_positions: HashMap<String, CursorPos>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LockfileHash {
+    pub lock_hash: String,
+    pub dependencies_hash: String,
+}
+
 #[tauri::command]
 pub async fn create_new_project(
     name: String,
@@ -127,16 +191,12 @@ See `requirements.txt` for project dependencies.
     }
 
     let uv_init_success = {
-        let mut cmd = Command::new("uv");
+        let mut cmd = uv_command();
         cmd.args(&uv_args)
             .current_dir(&project_path)
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
-        #[cfg(target_os = "windows")]
-        {
-            cmd.creation_flags(0x08000000);
-        }
 
         match cmd.output() {
             Ok(output) if output.status.success() => {
@@ -187,6 +247,8 @@ build-backend = "setuptools.build_meta"
         dependencies: vec![],
         created_at: now.clone(),
         last_opened: now,
+        active_venv: None,
+        run_configs: Vec::new(),
     };
 
     // Save project config
@@ -261,6 +323,8 @@ pub async fn load_project_config(project_path: String) -> Result<ProjectConfig,
             dependencies: vec![],
             created_at: now.clone(),
             last_opened: now,
+            active_venv: None,
+            run_configs: Vec::new(),
         };
 
         return Ok(config);
@@ -298,6 +362,102 @@ pub async fn save_project_config(config: ProjectConfig) -> Result<(), String> {
     Ok(())
 }
 
+// Save `config` into the project at `project_path`. The stored `config.path` is
+// wherever the project was created, which is stale once it is moved or cloned.
+async fn save_project_config_in(
+    project_path: String,
+    mut config: ProjectConfig,
+) -> Result<(), String> {
+    config.path = project_path;
+    save_project_config(config).await
+}
+
+#[tauri::command]
+pub async fn set_active_venv(project_path: String, name: Option<String>) -> Result<(), String> {
+    if let Some(ref name) = name {
+        if name.is_empty() || name.contains('/') || name.contains('\\') || name.contains("..") {
+            return Err(format!("Invalid virtual environment name '{}'", name));
+        }
+        if !Path::new(&project_path).join(".venvs").join(name).is_dir() {
+            return Err(format!("Virtual environment '{}' does not exist", name));
+        }
+    }
+
+    let mut config = load_project_config(project_path.clone()).await?;
+    config.active_venv = name;
+    save_project_config_in(project_path, config).await
+}
+
+// Named venv selected in .pyra-project.json, read without touching the file
+pub(crate) fn active_venv_name(project_path: &str) -> Option<String> {
+    fs::read_to_string(Path::new(project_path).join(".pyra-project.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|config| config["active_venv"].as_str().map(String::from))
+}
+
+// The project's active venv: `.venvs/<name>` when a named venv has been
+// selected, otherwise `.venv`
+pub(crate) fn venv_dir(project_path: &str) -> String {
+    match active_venv_name(project_path) {
+        Some(name) => format!("{}/.venvs/{}", project_path, name),
+        None => format!("{}/.venv", project_path),
+    }
+}
+
+// A `uv` command run in the project against its active venv. uv itself only
+// knows about `.venv`, so a selected named venv is passed through
+// UV_PROJECT_ENVIRONMENT for `uv run`, `uv sync` and `uv add`.
+pub(crate) fn uv_project_command(project_path: &str) -> Command {
+    let mut cmd = uv_command();
+    cmd.current_dir(project_path)
+        .env("UV_PROJECT_ENVIRONMENT", venv_dir(project_path));
+    cmd
+}
+
+pub(crate) fn venv_python(project_path: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("{}/Scripts/python.exe", venv_dir(project_path))
+    } else {
+        format!("{}/bin/python", venv_dir(project_path))
+    }
+}
+
+#[tauri::command]
+pub async fn save_session(project_path: String, session: EditorSession) -> Result<(), String> {
+    let session_path = Path::new(&project_path).join(".pyra-session.json");
+
+    let session_content = serde_json::to_string_pretty(&session)
+        .map_err(|e| format!("Failed to serialize session: {}", e))?;
+
+    fs::write(&session_path, session_content)
+        .map_err(|e| format!("Failed to save session: {}", e))
+}
+
+#[tauri::command]
+pub async fn load_session(project_path: String) -> Result<EditorSession, String> {
+    let session_path = Path::new(&project_path).join(".pyra-session.json");
+    if !session_path.exists() {
+        return Ok(EditorSession::default());
+    }
+
+    let session_content = fs::read_to_string(&session_path)
+        .map_err(|e| format!("Failed to read session: {}", e))?;
+    let mut session: EditorSession = serde_json::from_str(&session_content)
+        .map_err(|e| format!("Failed to parse session: {}", e))?;
+
+    // Drop files deleted or moved since the session was saved. Joining keeps
+    // absolute paths as they are and resolves relative ones against the project.
+    let exists = |file: &str| Path::new(&project_path).join(file).is_file();
+    session.open_files.retain(|file| exists(file));
+    session.cursor_positions.retain(|file, _| exists(file));
+    if !session.active_file.as_deref().map_or(false, exists) {
+        session.active_file = session.open_files.first().cloned();
+    }
+
+    Ok(session)
+}
+
 #[tauri::command]
 pub async fn get_recent_projects() -> Result<Vec<ProjectConfig>, String> {
     // This would typically read from a global config file
@@ -477,3 +637,343 @@ pub async fn check_pyproject_exists(project_path: String) -> bool {
     let pyproject_path = Path::new(&project_path).join("pyproject.toml");
     pyproject_path.exists()
 }
+
+#[tauri::command]
+pub async fn get_lockfile_hash(project_path: String) -> Result<LockfileHash, String> {
+    let project_dir = Path::new(&project_path);
+    let lock_path = project_dir.join("uv.lock");
+
+    if !lock_path.exists() {
+        return Err("uv.lock not found. Run uv sync or uv lock first.".to_string());
+    }
+
+    let lock_content = fs::read(&lock_path)
+        .map_err(|e| format!("Failed to read uv.lock: {}", e))?;
+    let lock_hash = format!("{:x}", Sha256::digest(&lock_content));
+
+    // Hash only the dependency tables so metadata edits don't count as drift
+    let pyproject_path = project_dir.join("pyproject.toml");
+    let pyproject_content = fs::read_to_string(&pyproject_path)
+        .map_err(|e| format!("Failed to read pyproject.toml: {}", e))?;
+    let value: toml::Value = pyproject_content.parse()
+        .map_err(|e| format!("Failed to parse pyproject.toml: {}", e))?;
+
+    let mut dependency_tables = toml::map::Map::new();
+    let sources = [
+        ("dependencies", value.get("project").and_then(|p| p.get("dependencies"))),
+        ("optional-dependencies", value.get("project").and_then(|p| p.get("optional-dependencies"))),
+        ("dependency-groups", value.get("dependency-groups")),
+        ("dev-dependencies", value.get("tool").and_then(|t| t.get("uv")).and_then(|uv| uv.get("dev-dependencies"))),
+    ];
+    for (key, table) in sources {
+        if let Some(table) = table {
+            dependency_tables.insert(key.to_string(), table.clone());
+        }
+    }
+
+    // Map keys are sorted, so the serialized form is stable across key reordering
+    let serialized = toml::to_string(&toml::Value::Table(dependency_tables))
+        .map_err(|e| format!("Failed to serialize dependency tables: {}", e))?;
+    let dependencies_hash = format!("{:x}", Sha256::digest(serialized.as_bytes()));
+
+    Ok(LockfileHash {
+        lock_hash,
+        dependencies_hash,
+    })
+}
+
+#[tauri::command]
+pub async fn rename_project(
+    project_path: String,
+    new_name: String,
+    rename_directory: bool,
+) -> Result<RenameProjectResult, String> {
+    let new_name = new_name.trim().to_string();
+    if new_name.is_empty() || new_name.contains(['/', '\\']) {
+        return Err(format!("Invalid project name: '{}'", new_name));
+    }
+
+    let mut project_dir = Path::new(&project_path).to_path_buf();
+    if !project_dir.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+
+    let mut warnings = Vec::new();
+
+    // Rename the directory first so the config can record the final path
+    if rename_directory {
+        let target = project_dir
+            .parent()
+            .ok_or("Cannot rename a root directory")?
+            .join(&new_name);
+        if target != project_dir {
+            if target.exists() {
+                return Err(format!("A directory named '{}' already exists", new_name));
+            }
+            fs::rename(&project_dir, &target)
+                .map_err(|e| format!("Failed to rename project directory: {}", e))?;
+            project_dir = target;
+
+            // venvs embed absolute paths in their scripts and pyvenv.cfg
+            if project_dir.join(".venv").exists() {
+                warnings.push(
+                    "The virtual environment contains absolute paths and may need to be recreated".to_string(),
+                );
+            }
+        }
+    }
+
+    // Update [project].name in pyproject.toml, keeping formatting and comments
+    let pyproject_path = project_dir.join("pyproject.toml");
+    if pyproject_path.exists() {
+        let content = fs::read_to_string(&pyproject_path)
+            .map_err(|e| format!("Failed to read pyproject.toml: {}", e))?;
+        let mut document: toml_edit::Document = content.parse()
+            .map_err(|e| format!("Failed to parse pyproject.toml: {}", e))?;
+
+        match document.get_mut("project").and_then(|p| p.as_table_like_mut()) {
+            Some(project) => {
+                project.insert("name", toml_edit::value(new_name.clone()));
+                fs::write(&pyproject_path, document.to_string())
+                    .map_err(|e| format!("Failed to write pyproject.toml: {}", e))?;
+            }
+            None => warnings.push("pyproject.toml has no [project] section".to_string()),
+        }
+    }
+
+    // Update the Pyra project config
+    let config_path = project_dir.join(".pyra-project.json");
+    if config_path.exists() {
+        let config_content = fs::read_to_string(&config_path)
+            .map_err(|e| format!("Failed to read project config: {}", e))?;
+        let mut config: ProjectConfig = serde_json::from_str(&config_content)
+            .map_err(|e| format!("Failed to parse project config: {}", e))?;
+
+        config.name = new_name.clone();
+        config.path = project_dir.to_string_lossy().to_string();
+
+        let updated_content = serde_json::to_string_pretty(&config)
+            .map_err(|e| format!("Failed to serialize project config: {}", e))?;
+        fs::write(&config_path, updated_content)
+            .map_err(|e| format!("Failed to save project config: {}", e))?;
+    }
+
+    Ok(RenameProjectResult {
+        project_path: project_dir.to_string_lossy().to_string(),
+        warnings,
+    })
+}
+
+#[tauri::command]
+pub async fn detect_run_config(project_path: String) -> Result<Vec<RunSuggestion>, String> {
+    let project_dir = Path::new(&project_path);
+    let mut suggestions = Vec::new();
+
+    let pyproject: Option<toml::Value> = fs::read_to_string(project_dir.join("pyproject.toml"))
+        .ok()
+        .and_then(|content| content.parse().ok());
+    let project_table = pyproject.as_ref().and_then(|v| v.get("project"));
+
+    // Plain entry scripts
+    for script in ["main.py", "src/main.py", "app.py"] {
+        if project_dir.join(script).is_file() {
+            suggestions.push(RunSuggestion {
+                label: format!("Run {}", script),
+                command: "python".to_string(),
+                args: vec![script.to_string()],
+            });
+        }
+    }
+
+    // FastAPI apps are served through uvicorn
+    let has_fastapi = project_table
+        .and_then(|p| p.get("dependencies"))
+        .and_then(|d| d.as_array())
+        .map(|deps| {
+            deps.iter()
+                .filter_map(|d| d.as_str())
+                .any(|d| dependency_name(d) == "fastapi")
+        })
+        .unwrap_or(false);
+    if has_fastapi {
+        let module = ["main.py", "app.py", "src/main.py"]
+            .iter()
+            .find(|file| {
+                fs::read_to_string(project_dir.join(file))
+                    .map(|content| content.contains("FastAPI("))
+                    .unwrap_or(false)
+            })
+            .map(|file| file.trim_end_matches(".py").replace('/', "."))
+            .unwrap_or_else(|| "main".to_string());
+        suggestions.push(RunSuggestion {
+            label: "Run FastAPI server (uvicorn)".to_string(),
+            command: "uvicorn".to_string(),
+            args: vec![format!("{}:app", module), "--reload".to_string()],
+        });
+    }
+
+    // Console entry points declared in [project.scripts]
+    if let Some(scripts) = project_table
+        .and_then(|p| p.get("scripts"))
+        .and_then(|s| s.as_table())
+    {
+        for (name, target) in scripts {
+            suggestions.push(RunSuggestion {
+                label: format!("Run script '{}' ({})", name, target.as_str().unwrap_or("")),
+                command: name.clone(),
+                args: vec![],
+            });
+        }
+    }
+
+    Ok(suggestions)
+}
+
+// Saved run configurations are looked up by name, so saving one under an
+// existing name replaces it
+#[tauri::command]
+pub async fn save_run_config(
+    project_path: String,
+    config: RunConfig,
+) -> Result<Vec<RunConfig>, String> {
+    if config.name.trim().is_empty() {
+        return Err("Run configuration needs a name".to_string());
+    }
+    if config.command.trim().is_empty() {
+        return Err("Run configuration needs a command".to_string());
+    }
+
+    let mut project_config = load_project_config(project_path.clone()).await?;
+    match project_config
+        .run_configs
+        .iter_mut()
+        .find(|existing| existing.name == config.name)
+    {
+        Some(existing) => *existing = config,
+        None => project_config.run_configs.push(config),
+    }
+    let run_configs = project_config.run_configs.clone();
+    save_project_config_in(project_path, project_config).await?;
+    Ok(run_configs)
+}
+
+// Read without touching the config's last_opened time
+#[tauri::command]
+pub async fn list_run_configs(project_path: String) -> Result<Vec<RunConfig>, String> {
+    let config_path = Path::new(&project_path).join(".pyra-project.json");
+    if !config_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let config_content = fs::read_to_string(&config_path)
+        .map_err(|e| format!("Failed to read project config: {}", e))?;
+    let config: ProjectConfig = serde_json::from_str(&config_content)
+        .map_err(|e| format!("Failed to parse project config: {}", e))?;
+    Ok(config.run_configs)
+}
+
+#[tauri::command]
+pub async fn delete_run_config(
+    project_path: String,
+    name: String,
+) -> Result<Vec<RunConfig>, String> {
+    let mut project_config = load_project_config(project_path.clone()).await?;
+    let before = project_config.run_configs.len();
+    project_config
+        .run_configs
+        .retain(|config| config.name != name);
+    if project_config.run_configs.len() == before {
+        return Err(format!("Run configuration '{}' not found", name));
+    }
+
+    let run_configs = project_config.run_configs.clone();
+    save_project_config_in(project_path, project_config).await?;
+    Ok(run_configs)
+}
+
+// Normalized package name from a PEP 508 requirement string
+fn dependency_name(requirement: &str) -> String {
+    requirement
+        .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_' || c == '.'))
+        .next()
+        .unwrap_or("")
+        .to_lowercase()
+        .replace('_', "-")
+}
+
+// Build a README.md from pyproject.toml. The content is only returned for
+// preview unless `write` is set, and an existing README is only replaced
+// when `overwrite` is set too.
+#[tauri::command]
+pub async fn generate_readme(
+    project_path: String,
+    write: bool,
+    overwrite: bool,
+) -> Result<GeneratedReadme, String> {
+    let pyproject = read_pyproject_toml(project_path.clone()).await?;
+    let run_suggestions = detect_run_config(project_path.clone()).await.unwrap_or_default();
+    let metadata = &pyproject.project;
+
+    let mut content = format!("# {}\n\n", metadata.name);
+    if let Some(description) = metadata.description.as_ref().filter(|d| !d.trim().is_empty()) {
+        content.push_str(&format!("{}\n\n", description.trim()));
+    }
+
+    content.push_str("## Requirements\n\n");
+    match &metadata.requires_python {
+        Some(requires_python) => content.push_str(&format!("- Python {}\n", requires_python)),
+        None => content.push_str("- Python 3\n"),
+    }
+    content.push_str("- [uv](https://docs.astral.sh/uv/)\n\n");
+
+    content.push_str("## Installation\n\n```bash\nuv sync\n```\n\n");
+
+    content.push_str("## Usage\n\n");
+    if run_suggestions.is_empty() {
+        content.push_str("```bash\nuv run python main.py\n```\n\n");
+    } else {
+        content.push_str("```bash\n");
+        for suggestion in &run_suggestions {
+            let mut command = format!("uv run {}", suggestion.command);
+            for arg in &suggestion.args {
+                command.push(' ');
+                command.push_str(arg);
+            }
+            content.push_str(&format!("{}\n", command));
+        }
+        content.push_str("```\n\n");
+    }
+
+    if !pyproject.dependencies.is_empty() {
+        content.push_str("## Dependencies\n\n");
+        for dependency in &pyproject.dependencies {
+            content.push_str(&format!("- `{}`\n", dependency));
+        }
+        content.push('\n');
+    }
+
+    if let Some(license) = &metadata.license {
+        content.push_str(&format!("## License\n\n{}\n", license));
+    }
+    let content = format!("{}\n", content.trim_end());
+
+    let readme_path = Path::new(&project_path).join("README.md");
+    let existed = readme_path.exists();
+    let written = if write {
+        if existed && !overwrite {
+            return Err("README.md already exists; enable overwrite to replace it".to_string());
+        }
+        write_file_atomic(&readme_path, content.as_bytes())
+            .map_err(|e| format!("Failed to write README.md: {}", e))?;
+        true
+    } else {
+        false
+    };
+
+    Ok(GeneratedReadme {
+        content,
+        path: readme_path.to_string_lossy().to_string(),
+        existed,
+        written,
+    })
+}
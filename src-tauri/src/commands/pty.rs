@@ -0,0 +1,193 @@
+use portable_pty::{native_pty_system, ChildKiller, CommandBuilder, MasterPty, PtySize};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{Emitter, State, Window};
+use tokio::sync::Mutex;
+
+use super::process::read_utf8_chunks;
+use super::project::{venv_dir, venv_python};
+use super::settings::uv_program;
+
+pub struct PtySession {
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    killer: Box<dyn ChildKiller + Send + Sync>,
+    pid: Option<u32>,
+}
+
+// Running PTY sessions keyed by the id the frontend chose for them
+pub type PtyManager = Arc<Mutex<HashMap<String, PtySession>>>;
+
+pub fn create_pty_manager() -> PtyManager {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PtyOutput {
+    pub id: String,
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PtyExit {
+    pub id: String,
+    pub exit_code: Option<u32>,
+}
+
+// Run a script attached to a pseudo-terminal, so it sees a real TTY (colors,
+// line buffering, `input()` prompts). Output arrives as `pty-output` events
+// and a `pty-exit` event follows when the script ends.
+#[tauri::command]
+pub async fn run_script_pty(
+    window: Window,
+    project_path: String,
+    script_path: String,
+    id: String,
+    pty_manager: State<'_, PtyManager>,
+) -> Result<String, String> {
+    // Replace any session still running under this id
+    if let Some(mut previous) = pty_manager.lock().await.remove(&id) {
+        let _ = previous.killer.kill();
+    }
+
+    let pair = native_pty_system()
+        .openpty(PtySize {
+            rows: 24,
+            cols: 80,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(|e| format!("Failed to open a pseudo-terminal: {}", e))?;
+
+    let python_exe = venv_python(&project_path);
+    let mut cmd = if Path::new(&python_exe).exists() {
+        CommandBuilder::new(&python_exe)
+    } else {
+        let mut cmd = CommandBuilder::new(uv_program());
+        cmd.args(["run", "python"]);
+        cmd.env("UV_PROJECT_ENVIRONMENT", venv_dir(&project_path));
+        cmd
+    };
+    cmd.arg(&script_path);
+    cmd.cwd(&project_path);
+    cmd.env("TERM", "xterm-256color");
+
+    let mut child = pair
+        .slave
+        .spawn_command(cmd)
+        .map_err(|e| format!("Failed to start script: {}", e))?;
+    // Only the child should hold the slave side, so reads end when it exits
+    drop(pair.slave);
+
+    let reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|e| format!("Failed to read from the pseudo-terminal: {}", e))?;
+    let writer = pair
+        .master
+        .take_writer()
+        .map_err(|e| format!("Failed to write to the pseudo-terminal: {}", e))?;
+    let pid = child.process_id();
+
+    pty_manager.lock().await.insert(
+        id.clone(),
+        PtySession {
+            master: pair.master,
+            writer,
+            killer: child.clone_killer(),
+            pid,
+        },
+    );
+
+    let output_window = window.clone();
+    let output_id = id.clone();
+    let output_handle = std::thread::spawn(move || {
+        read_utf8_chunks(reader, |data| {
+            let _ = output_window.emit(
+                "pty-output",
+                PtyOutput {
+                    id: output_id.clone(),
+                    data,
+                },
+            );
+        });
+    });
+
+    let manager = Arc::clone(&*pty_manager);
+    tokio::spawn(async move {
+        let status = tokio::task::spawn_blocking(move || child.wait()).await;
+
+        // Dropping the master lets the reader finish on Windows, where the
+        // terminal stays open after the child exits. Leave the entry alone if
+        // the id has been reused by a newer session.
+        {
+            let mut sessions = manager.lock().await;
+            if sessions
+                .get(&id)
+                .map_or(false, |session| session.pid == pid)
+            {
+                sessions.remove(&id);
+            }
+        }
+        let _ = tokio::task::spawn_blocking(move || output_handle.join()).await;
+
+        let exit_code = match status {
+            Ok(Ok(status)) => Some(status.exit_code()),
+            _ => None,
+        };
+        let _ = window.emit("pty-exit", PtyExit { id, exit_code });
+    });
+
+    Ok("Script started in terminal".to_string())
+}
+
+// Write keystrokes or pasted text to a running PTY session
+#[tauri::command]
+pub async fn send_pty_input(
+    id: String,
+    data: String,
+    pty_manager: State<'_, PtyManager>,
+) -> Result<(), String> {
+    let mut sessions = pty_manager.lock().await;
+    let session = sessions
+        .get_mut(&id)
+        .ok_or_else(|| format!("No terminal session '{}'", id))?;
+
+    session
+        .writer
+        .write_all(data.as_bytes())
+        .and_then(|_| session.writer.flush())
+        .map_err(|e| format!("Failed to write to terminal: {}", e))
+}
+
+// Match the PTY to the terminal widget so programs that query the terminal
+// size (shutil.get_terminal_size, curses) lay out correctly
+#[tauri::command]
+pub async fn resize_pty(
+    id: String,
+    rows: u16,
+    cols: u16,
+    pty_manager: State<'_, PtyManager>,
+) -> Result<(), String> {
+    if rows == 0 || cols == 0 {
+        return Err(format!("Invalid terminal size {}x{}", cols, rows));
+    }
+
+    let sessions = pty_manager.lock().await;
+    let session = sessions
+        .get(&id)
+        .ok_or_else(|| format!("No terminal session '{}'", id))?;
+
+    session
+        .master
+        .resize(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(|e| format!("Failed to resize terminal: {}", e))
+}
@@ -1,15 +1,25 @@
 ﻿use serde::{Deserialize, Serialize};
-use std::io::{BufRead, BufReader};
-use std::path::Path;
-use std::process::{Child, Command, Stdio};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
-use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex as StdMutex};
 use tauri::{Emitter, State, Window};
 use tokio::sync::Mutex;
 
+use super::process::{new_process_group, read_utf8_chunks, ProcessTree};
+use super::project::{
+    active_venv_name, read_pyproject_toml, uv_project_command, venv_dir, venv_python,
+};
+use super::settings::uv_command;
+use super::watch::{self, WatchManager};
+
 // Global process manager to track running processes
-type ProcessManager = Arc<Mutex<Option<Child>>>;
+type ProcessManager = Arc<Mutex<Option<ProcessTree>>>;
 
 pub fn create_process_manager() -> ProcessManager {
     Arc::new(Mutex::new(None))
@@ -29,23 +39,188 @@ pub struct PackageWithDeps {
     pub depth: u32,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PackageChange {
+    pub name: String,
+    pub old_version: Option<String>,
+    pub new_version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct LockDiff {
+    pub added: Vec<PackageChange>,
+    pub removed: Vec<PackageChange>,
+    pub updated: Vec<PackageChange>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UvOperationResult {
+    pub output: String,
+    pub lock_diff: LockDiff,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BulkInstallResult {
+    pub installed: Vec<String>,
+    pub failed: Vec<String>,
+    pub output: String,
+    // uv resolves the batch as a whole, so one bad package fails them all
+    pub batch_failed: bool,
+    pub lock_diff: LockDiff,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct DependencyTree {
     pub packages: Vec<PackageWithDeps>,
     pub total_count: u32,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct UvVersionInfo {
+    pub version: String,
+    pub minimum_version: String,
+    pub meets_minimum: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PackageLicense {
+    pub name: String,
+    pub version: String,
+    pub license: String,
+    // Unknown or GPL-family licenses that need a closer look
+    pub flagged: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UvTool {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PythonVersion {
+    pub key: String,
+    pub implementation: String,
+    pub version: String,
+    pub installed: bool,
+    pub path: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProfileEntry {
+    pub function: String,
+    pub file: String,
+    pub line: u32,
+    pub calls: u64,
+    pub total_time: f64,
+    pub cumulative_time: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FailedRequirement {
+    pub requirement: String,
+    pub error: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RequirementsImportResult {
+    pub added: Vec<String>,
+    pub failed: Vec<FailedRequirement>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct VenvInfo {
+    pub name: String,
+    pub path: String,
+    pub python_version: Option<String>,
+    pub active: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MemorySample {
+    pub elapsed_ms: u64,
+    pub rss_bytes: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MemoryRunResult {
+    pub peak_memory_bytes: u64,
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FigureRunResult {
+    pub output: String,
+    pub success: bool,
+    pub figures: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TracebackFrame {
+    pub file: String,
+    pub line: u32,
+    // Empty for syntax errors, which have no enclosing function
+    pub function: String,
+    pub code: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ExceptionTrace {
+    pub exception_type: String,
+    pub message: String,
+    pub frames: Vec<TracebackFrame>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TracebackRunResult {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: Option<i32>,
+    // The exception that ended the script, when it crashed
+    pub exception: Option<ExceptionTrace>,
+    // Exceptions it was raised from or while handling, oldest first
+    pub chained: Vec<ExceptionTrace>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ScriptLogEntry {
+    pub level: String,
+    pub logger: Option<String>,
+    pub message: String,
+    pub stream: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RawOutputChunk {
+    pub stream: String,
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AutosyncEvent {
+    pub watch_id: String,
+    pub success: bool,
+    pub output: String,
+    pub lock_diff: Option<LockDiff>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SnippetResult {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+}
+
 #[tauri::command]
 pub async fn check_uv_installed() -> Result<bool, String> {
-    let mut cmd = Command::new("uv");
+    let mut cmd = uv_command();
     cmd.arg("--version")
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output();
 
     match output {
@@ -107,245 +282,287 @@ pub async fn ensure_uv_installed() -> Result<String, String> {
         }
     }
 }
+
+// Oldest uv the IDE works with
+const MIN_UV_VERSION: &str = "0.3.0";
+
+fn installed_uv_version() -> Result<semver::Version, String> {
+    let mut cmd = uv_command();
+    cmd.arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    // Output looks like "uv 0.4.18 (7b55e9790 2024-10-01)"
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let version = stdout
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| format!("Unexpected uv --version output: {}", stdout.trim()))?;
+    semver::Version::parse(version).map_err(|e| format!("Failed to parse uv version '{}': {}", version, e))
+}
+
 #[tauri::command]
-pub async fn list_python_versions() -> Result<Vec<String>, String> {
-    let mut cmd = Command::new("uv");
-    cmd.args(&["python", "list"])
+pub async fn check_uv_version() -> Result<UvVersionInfo, String> {
+    let version = installed_uv_version()?;
+    let minimum = semver::Version::parse(MIN_UV_VERSION).unwrap();
+
+    Ok(UvVersionInfo {
+        version: version.to_string(),
+        minimum_version: MIN_UV_VERSION.to_string(),
+        meets_minimum: version >= minimum,
+    })
+}
+
+#[tauri::command]
+pub async fn update_uv() -> Result<String, String> {
+    let mut cmd = uv_command();
+    cmd.args(["self", "update"])
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    // Fails when uv was installed by a package manager (pip, Homebrew, ...)
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
     }
+
+    Ok(installed_uv_version()?.to_string())
+}
+
+#[tauri::command]
+pub async fn list_uv_tools() -> Result<Vec<UvTool>, String> {
+    let mut cmd = uv_command();
+    cmd.args(["tool", "list"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv: {}", e))?;
 
     if output.status.success() {
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let versions: Vec<String> = stdout
+        // Output looks like "ruff v0.5.0" followed by "- ruff" lines listing
+        // the executables the tool provides. "No tools installed" goes to stderr.
+        let tools: Vec<UvTool> = stdout
             .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('-'))
             .filter_map(|line| {
-                // Parse uv python list output
-                if line.contains("python") {
-                    line.split_whitespace().next().map(|s| s.to_string())
-                } else {
-                    None
-                }
+                let mut parts = line.split_whitespace();
+                let name = parts.next()?;
+                let version = parts.next()?.trim_start_matches('v');
+                Some(UvTool {
+                    name: name.to_string(),
+                    version: version.to_string(),
+                })
             })
             .collect();
-        Ok(versions)
+        Ok(tools)
     } else {
         Err(String::from_utf8_lossy(&output.stderr).to_string())
     }
 }
 
 #[tauri::command]
-pub async fn install_python_version(version: String) -> Result<String, String> {
-    let mut cmd = Command::new("uv");
-    cmd.args(&["python", "install", &version])
+pub async fn install_uv_tool(name: String) -> Result<String, String> {
+    let mut cmd = uv_command();
+    cmd.args(["tool", "install", &name])
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv: {}", e))?;
 
     if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Ok(format!("{}{}", stdout, stderr))
     } else {
         Err(String::from_utf8_lossy(&output.stderr).to_string())
     }
 }
 
 #[tauri::command]
-pub async fn create_venv(
-    project_path: String,
-    python_version: Option<String>,
-) -> Result<String, String> {
-    let mut args = vec!["venv", ".venv"];
-
-    // Add python version if specified
-    let python_arg;
-    if let Some(version) = python_version {
-        python_arg = format!("--python={}", version);
-        args.push(&python_arg);
-    }
-
-    let mut cmd = Command::new("uv");
-    cmd.args(&args)
-        .current_dir(&project_path)
+pub async fn uninstall_uv_tool(name: String) -> Result<String, String> {
+    let mut cmd = uv_command();
+    cmd.args(["tool", "uninstall", &name])
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv: {}", e))?;
 
     if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Ok(format!("{}{}", stdout, stderr))
     } else {
         Err(String::from_utf8_lossy(&output.stderr).to_string())
     }
 }
 
 #[tauri::command]
-pub async fn check_venv_exists(project_path: String) -> bool {
-    let venv_path = Path::new(&project_path).join(".venv");
-    venv_path.exists() && venv_path.is_dir()
-}
-
-#[tauri::command]
-pub async fn install_package(project_path: String, package: String) -> Result<String, String> {
-    // Check if project has pyproject.toml (UV project)
-    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
-    if !pyproject_path.exists() {
-        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
-    }
-
-    let mut cmd = Command::new("uv");
-    cmd.args(&["add", &package])
-        .current_dir(&project_path)
+pub async fn list_python_versions() -> Result<Vec<String>, String> {
+    let mut cmd = uv_command();
+    cmd.args(&["python", "list"])
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv: {}", e))?;
 
     if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let versions: Vec<String> = stdout
+            .lines()
+            .filter_map(|line| {
+                // Parse uv python list output
+                if line.contains("python") {
+                    line.split_whitespace().next().map(|s| s.to_string())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        Ok(versions)
     } else {
         Err(String::from_utf8_lossy(&output.stderr).to_string())
     }
 }
 
 #[tauri::command]
-pub async fn uninstall_package(project_path: String, package: String) -> Result<String, String> {
-    // Check if project has pyproject.toml (UV project)
-    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
-    if !pyproject_path.exists() {
-        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
-    }
-
-    let mut cmd = Command::new("uv");
-    cmd.args(&["remove", &package])
-        .current_dir(&project_path)
+pub async fn get_available_python_versions() -> Result<Vec<PythonVersion>, String> {
+    let mut cmd = uv_command();
+    cmd.args(["python", "list", "--all-versions"])
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv: {}", e))?;
 
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
     }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let versions = stdout
+        .lines()
+        .filter_map(|line| {
+            // "cpython-3.12.5-macos-aarch64-none    /usr/bin/python3.12" or
+            // "cpython-3.13.0-macos-aarch64-none    <download available>"
+            let mut parts = line.split_whitespace();
+            let key = parts.next()?;
+            let location = parts.collect::<Vec<_>>().join(" ");
+
+            let mut key_parts = key.split('-');
+            let implementation = key_parts.next()?.to_string();
+            let version = key_parts.next()?.to_string();
+
+            let installed = !location.is_empty() && !location.starts_with("<download");
+            Some(PythonVersion {
+                key: key.to_string(),
+                implementation,
+                version,
+                installed,
+                path: if installed { Some(location) } else { None },
+            })
+        })
+        .collect();
+
+    Ok(versions)
 }
 
-#[tauri::command]
-pub async fn get_dependency_tree(project_path: String) -> Result<DependencyTree, String> {
-    // Check if project has pyproject.toml (UV project)
-    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
-    if !pyproject_path.exists() {
-        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
-    }
+#[derive(Serialize, Deserialize)]
+pub struct DiscoveredPython {
+    pub path: String,
+    pub version: String,
+    // Where it was found: "path", "pyenv", "homebrew", "system" or "py-launcher"
+    pub source: String,
+}
 
-    // Use uv tree to show detailed dependencies
-    let mut cmd = Command::new("uv");
-    cmd.args(&["tree", "--depth", "3"])
-        .current_dir(&project_path)
+// `python`, `python3` and `python3.X` (with `.exe` on Windows)
+fn is_python_executable_name(name: &str) -> bool {
+    let name = name.strip_suffix(".exe").unwrap_or(name);
+    let version = match name.strip_prefix("python") {
+        Some(version) => version,
+        None => return false,
+    };
+    version.is_empty()
+        || version == "3"
+        || version.strip_prefix("3.").map_or(false, |minor| {
+            !minor.is_empty() && minor.chars().all(|c| c.is_ascii_digit())
+        })
+}
+
+fn python_executables_in(dir: &Path) -> Vec<PathBuf> {
+    let mut executables: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| is_python_executable_name(&entry.file_name().to_string_lossy()))
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .collect()
+        })
+        .unwrap_or_default();
+    executables.sort();
+    executables
+}
+
+// Subdirectories of `dir`, each joined with `relative`
+fn versioned_dirs(dir: &Path, relative: &str) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path().join(relative))
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+// Interpreters registered with the Windows `py` launcher. `py -0p` lists
+// lines like " -V:3.12 *        C:\Python312\python.exe"
+#[cfg(target_os = "windows")]
+fn py_launcher_pythons() -> Vec<PathBuf> {
+    let mut cmd = Command::new("py");
+    cmd.arg("-0p")
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
-    let output = cmd.output()
-        .map_err(|e| format!("Failed to execute uv: {}", e))?;
-
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut packages = Vec::new();
-        let mut current_package: Option<PackageWithDeps> = None;
-        let mut total_count = 0;
-
-        for line in stdout.lines() {
-            let prefix_len = line.chars().take_while(|c| matches!(c, '│' | '├' | '└' | '─' | ' ')).count();
-            let depth = prefix_len / 4;
-            
-            // Clean the line from tree characters
-            let cleaned_line = line
-                .chars()
-                .skip_while(|c| matches!(c, '│' | '├' | '└' | '─' | ' '))
-                .collect::<String>();
-
-            if cleaned_line.contains(" v") {
-                if let Some(version_pos) = cleaned_line.find(" v") {
-                    let name = cleaned_line[..version_pos].trim().to_string();
-                    let version = cleaned_line[version_pos + 2..].trim().to_string();
-                    
-                    if depth == 0 {
-                        // Root level package - save previous and start new
-                        if let Some(pkg) = current_package.take() {
-                            packages.push(pkg);
-                        }
-                        current_package = Some(PackageWithDeps {
-                            name: name.clone(),
-                            version: version.clone(),
-                            dependencies: Vec::new(),
-                            depth: depth as u32,
-                        });
-                        total_count += 1;
-                    } else if let Some(ref mut current) = current_package {
-                        // Dependency of current package
-                        current.dependencies.push(Package { name, version });
-                    }
-                }
-            }
-        }
-
-        // Add the last package
-        if let Some(pkg) = current_package {
-            packages.push(pkg);
-        }
+    cmd.creation_flags(0x08000000);
+    let output = match cmd.output() {
+        Ok(output) if output.status.success() => output,
+        _ => return Vec::new(),
+    };
 
-        Ok(DependencyTree {
-            packages,
-            total_count,
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            // The path is the rest of the line from its drive letter
+            let drive = line.find(":\\")?.checked_sub(1)?;
+            Some(PathBuf::from(line[drive..].trim()))
         })
-    } else {
-        Err(String::from_utf8_lossy(&output.stderr).to_string())
-    }
+        .collect()
 }
 
-#[tauri::command]
-pub async fn list_packages(project_path: String) -> Result<Vec<Package>, String> {
-    // Check if project has pyproject.toml (UV project)
-    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
-    if !pyproject_path.exists() {
-        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
-    }
+#[cfg(not(target_os = "windows"))]
+fn py_launcher_pythons() -> Vec<PathBuf> {
+    Vec::new()
+}
 
-    // Use uv tree to show dependencies
-    let mut cmd = Command::new("uv");
-    cmd.args(&["tree"])
-        .current_dir(&project_path)
+fn python_version_of(executable: &Path) -> Option<String> {
+    let mut cmd = Command::new(executable);
+    cmd.arg("--version")
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
@@ -353,8 +570,1041 @@ pub async fn list_packages(project_path: String) -> Result<Vec<Package>, String>
     {
         cmd.creation_flags(0x08000000);
     }
-    let output = cmd.output()
-        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+    let output = cmd.output().ok().filter(|output| output.status.success())?;
+
+    // Python 2 prints its version to stderr
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    text.trim()
+        .strip_prefix("Python ")
+        .map(|version| version.trim().to_string())
+}
+
+// Python interpreters installed outside uv, for creating a venv with
+// `--python <path>`. Executables reached through several names or symlinks
+// are listed once, under the first place they were found.
+#[tauri::command]
+pub async fn discover_system_pythons() -> Result<Vec<DiscoveredPython>, String> {
+    let mut candidates: Vec<(PathBuf, &str)> = Vec::new();
+
+    if let Some(path) = std::env::var_os("PATH") {
+        for dir in std::env::split_paths(&path) {
+            // pyenv shims and the Windows Store aliases aren't interpreters
+            let dir_name = dir.to_string_lossy();
+            if dir_name.ends_with("shims") || dir_name.contains("WindowsApps") {
+                continue;
+            }
+            candidates.extend(
+                python_executables_in(&dir)
+                    .into_iter()
+                    .map(|exe| (exe, "path")),
+            );
+        }
+    }
+
+    let pyenv_root = std::env::var_os("PYENV_ROOT")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".pyenv")));
+    if let Some(root) = pyenv_root {
+        let (versions, bin) = if cfg!(target_os = "windows") {
+            (root.join("pyenv-win").join("versions"), "")
+        } else {
+            (root.join("versions"), "bin")
+        };
+        for dir in versioned_dirs(&versions, bin) {
+            candidates.extend(
+                python_executables_in(&dir)
+                    .into_iter()
+                    .map(|exe| (exe, "pyenv")),
+            );
+        }
+    }
+
+    if !cfg!(target_os = "windows") {
+        for prefix in ["/opt/homebrew", "/usr/local", "/home/linuxbrew/.linuxbrew"] {
+            let prefix = Path::new(prefix);
+            // /usr/local only belongs to Homebrew on Intel Macs that have it
+            let source = if prefix.join("Cellar").is_dir() {
+                "homebrew"
+            } else {
+                "system"
+            };
+            candidates.extend(
+                python_executables_in(&prefix.join("bin"))
+                    .into_iter()
+                    .map(|exe| (exe, source)),
+            );
+            for dir in versioned_dirs(&prefix.join("opt"), "bin") {
+                let is_python = dir
+                    .parent()
+                    .and_then(|formula| formula.file_name())
+                    .map_or(false, |name| name.to_string_lossy().starts_with("python@"));
+                if is_python {
+                    candidates.extend(
+                        python_executables_in(&dir)
+                            .into_iter()
+                            .map(|exe| (exe, "homebrew")),
+                    );
+                }
+            }
+        }
+
+        let mut system_dirs = vec![PathBuf::from("/usr/bin")];
+        system_dirs.extend(versioned_dirs(
+            Path::new("/Library/Frameworks/Python.framework/Versions"),
+            "bin",
+        ));
+        for dir in system_dirs {
+            candidates.extend(
+                python_executables_in(&dir)
+                    .into_iter()
+                    .map(|exe| (exe, "system")),
+            );
+        }
+    }
+
+    candidates.extend(
+        py_launcher_pythons()
+            .into_iter()
+            .map(|exe| (exe, "py-launcher")),
+    );
+
+    if cfg!(target_os = "windows") {
+        // Installs the py launcher doesn't know about: per-user ones under
+        // %LOCALAPPDATA%\Programs\Python, all-users ones under Program Files,
+        // and C:\PythonXY from older installers
+        let mut roots = vec![PathBuf::from("C:\\")];
+        roots.extend(std::env::var_os("ProgramFiles").map(PathBuf::from));
+        roots.extend(
+            std::env::var_os("LOCALAPPDATA")
+                .map(|local| PathBuf::from(local).join("Programs").join("Python")),
+        );
+        for root in roots {
+            for dir in versioned_dirs(&root, "") {
+                let is_python = dir
+                    .file_name()
+                    .map_or(false, |name| name.to_string_lossy().starts_with("Python"));
+                if is_python {
+                    candidates.extend(
+                        python_executables_in(&dir)
+                            .into_iter()
+                            .map(|exe| (exe, "system")),
+                    );
+                }
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut pythons = Vec::new();
+    for (executable, source) in candidates {
+        let resolved = match std::fs::canonicalize(&executable) {
+            Ok(resolved) => resolved,
+            Err(_) => continue,
+        };
+        if !seen.insert(resolved) {
+            continue;
+        }
+        if let Some(version) = python_version_of(&executable) {
+            pythons.push(DiscoveredPython {
+                path: executable.to_string_lossy().to_string(),
+                version,
+                source: source.to_string(),
+            });
+        }
+    }
+
+    Ok(pythons)
+}
+
+#[tauri::command]
+pub async fn install_python_version(version: String) -> Result<String, String> {
+    let mut cmd = uv_command();
+    cmd.args(&["python", "install", &version])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+// Numeric release segments of a version like "3.12.5". Pre-releases and
+// other non-numeric versions give None.
+fn release_segments(version: &str) -> Option<Vec<u64>> {
+    version
+        .trim()
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+fn compare_release(a: &[u64], b: &[u64]) -> std::cmp::Ordering {
+    let len = a.len().max(b.len());
+    let segment = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+    (0..len)
+        .map(|i| segment(a, i).cmp(&segment(b, i)))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(std::cmp::Ordering::Equal)
+}
+
+// Whether a release satisfies a PEP 440 specifier set such as ">=3.9,<3.12".
+// Unparseable clauses reject the version.
+fn python_version_satisfies(version: &[u64], specifier: &str) -> bool {
+    use std::cmp::Ordering;
+
+    specifier
+        .split(',')
+        .map(str::trim)
+        .filter(|clause| !clause.is_empty())
+        .all(|clause| {
+            let op_len = clause
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(clause.len());
+            let (op, target) = (clause[..op_len].trim(), clause[op_len..].trim());
+
+            // `==3.11.*` and `!=3.11.*` compare only the given prefix
+            if let Some(prefix) = target.strip_suffix(".*") {
+                let prefix = match release_segments(prefix) {
+                    Some(prefix) => prefix,
+                    None => return false,
+                };
+                let matches = compare_release(&version[..prefix.len().min(version.len())], &prefix)
+                    == Ordering::Equal;
+                return match op {
+                    "==" => matches,
+                    "!=" => !matches,
+                    _ => false,
+                };
+            }
+
+            let target = match release_segments(target) {
+                Some(target) => target,
+                None => return false,
+            };
+            let ordering = compare_release(version, &target);
+            match op {
+                ">=" => ordering != Ordering::Less,
+                ">" => ordering == Ordering::Greater,
+                "<=" => ordering != Ordering::Greater,
+                "<" => ordering == Ordering::Less,
+                "==" | "===" => ordering == Ordering::Equal,
+                "!=" => ordering != Ordering::Equal,
+                // ~=3.11 means >=3.11 and ==3.*, ~=3.11.2 means >=3.11.2 and ==3.11.*
+                "~=" if target.len() > 1 => {
+                    let prefix = &target[..target.len() - 1];
+                    ordering != Ordering::Less
+                        && compare_release(&version[..prefix.len().min(version.len())], prefix)
+                            == Ordering::Equal
+                }
+                _ => false,
+            }
+        })
+}
+
+// Install the newest CPython release allowed by the project's
+// `requires-python` and return its version
+#[tauri::command]
+pub async fn install_required_python(project_path: String) -> Result<String, String> {
+    let pyproject = read_pyproject_toml(project_path).await?;
+    let requires_python = pyproject
+        .project
+        .requires_python
+        .ok_or_else(|| "pyproject.toml does not specify requires-python".to_string())?;
+
+    let candidates = get_available_python_versions().await?;
+    let newest = candidates
+        .into_iter()
+        .filter(|candidate| candidate.implementation == "cpython")
+        .filter_map(|candidate| {
+            let segments = release_segments(&candidate.version)?;
+            if python_version_satisfies(&segments, &requires_python) {
+                Some((segments, candidate))
+            } else {
+                None
+            }
+        })
+        // Prefer an installed copy when the same version is listed twice
+        .max_by(|(a, a_candidate), (b, b_candidate)| {
+            compare_release(a, b).then(a_candidate.installed.cmp(&b_candidate.installed))
+        });
+
+    let candidate = match newest {
+        Some((_, candidate)) => candidate,
+        None => {
+            return Err(format!(
+                "No available Python version satisfies requires-python {}",
+                requires_python
+            ))
+        }
+    };
+
+    if !candidate.installed {
+        install_python_version(candidate.version.clone()).await?;
+    }
+    Ok(candidate.version)
+}
+
+#[tauri::command]
+pub async fn create_venv(
+    project_path: String,
+    python_version: Option<String>,
+) -> Result<String, String> {
+    let mut args = vec!["venv", ".venv"];
+
+    // Add python version if specified
+    let python_arg;
+    if let Some(version) = python_version {
+        python_arg = format!("--python={}", version);
+        args.push(&python_arg);
+    }
+
+    let mut cmd = uv_command();
+    cmd.args(&args)
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+// Names become directories under .venvs/, so keep them to a single path segment
+fn validate_venv_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name != "."
+        && name != ".."
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid virtual environment name: '{}'", name))
+    }
+}
+
+#[tauri::command]
+pub async fn create_named_venv(
+    project_path: String,
+    name: String,
+    python_version: Option<String>,
+) -> Result<String, String> {
+    validate_venv_name(&name)?;
+
+    let venv_dir = format!(".venvs/{}", name);
+    let mut args = vec!["venv".to_string(), venv_dir];
+    if let Some(version) = python_version {
+        args.push(format!("--python={}", version));
+    }
+
+    let mut cmd = uv_command();
+    cmd.args(&args)
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    if output.status.success() {
+        // uv reports progress on stderr
+        Ok(String::from_utf8_lossy(&output.stderr).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn list_venvs(project_path: String) -> Result<Vec<VenvInfo>, String> {
+    let venvs_dir = Path::new(&project_path).join(".venvs");
+    if !venvs_dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let active = active_venv_name(&project_path);
+
+    let entries = std::fs::read_dir(&venvs_dir)
+        .map_err(|e| format!("Failed to read .venvs: {}", e))?;
+
+    let mut venvs = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let cfg_path = path.join("pyvenv.cfg");
+        if !cfg_path.is_file() {
+            continue;
+        }
+
+        // uv writes `version_info`, the venv module writes `version`
+        let python_version = std::fs::read_to_string(&cfg_path).ok().and_then(|cfg| {
+            cfg.lines().find_map(|line| {
+                let (key, value) = line.split_once('=')?;
+                matches!(key.trim(), "version_info" | "version").then(|| value.trim().to_string())
+            })
+        });
+
+        let name = entry.file_name().to_string_lossy().to_string();
+        venvs.push(VenvInfo {
+            active: active.as_deref() == Some(name.as_str()),
+            name,
+            path: path.to_string_lossy().to_string(),
+            python_version,
+        });
+    }
+
+    venvs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(venvs)
+}
+
+#[tauri::command]
+pub async fn check_venv_exists(project_path: String) -> bool {
+    let venv_path = Path::new(&project_path).join(".venv");
+    venv_path.exists() && venv_path.is_dir()
+}
+
+fn read_lockfile(project_path: &str) -> Option<String> {
+    std::fs::read_to_string(Path::new(project_path).join("uv.lock")).ok()
+}
+
+// Package name -> version from the `[[package]]` entries of a uv.lock. A
+// package locked at several versions (e.g. per platform) lists them all.
+fn lock_packages(content: &str) -> BTreeMap<String, String> {
+    let mut versions: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let lock: toml::Value = match content.parse() {
+        Ok(lock) => lock,
+        Err(_) => return BTreeMap::new(),
+    };
+    let packages = lock.get("package").and_then(|p| p.as_array());
+    for package in packages.into_iter().flatten() {
+        if let Some(name) = package.get("name").and_then(|n| n.as_str()) {
+            let version = package.get("version").and_then(|v| v.as_str()).unwrap_or("");
+            versions.entry(name.to_string()).or_default().push(version.to_string());
+        }
+    }
+    versions
+        .into_iter()
+        .map(|(name, mut versions)| {
+            versions.sort();
+            versions.dedup();
+            (name, versions.join(", "))
+        })
+        .collect()
+}
+
+fn diff_lockfiles(before: Option<&str>, after: Option<&str>) -> LockDiff {
+    let before = before.map(lock_packages).unwrap_or_default();
+    let after = after.map(lock_packages).unwrap_or_default();
+    let mut diff = LockDiff::default();
+
+    for (name, new_version) in &after {
+        match before.get(name) {
+            None => diff.added.push(PackageChange {
+                name: name.clone(),
+                old_version: None,
+                new_version: Some(new_version.clone()),
+            }),
+            Some(old_version) if old_version != new_version => diff.updated.push(PackageChange {
+                name: name.clone(),
+                old_version: Some(old_version.clone()),
+                new_version: Some(new_version.clone()),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (name, old_version) in &before {
+        if !after.contains_key(name) {
+            diff.removed.push(PackageChange {
+                name: name.clone(),
+                old_version: Some(old_version.clone()),
+                new_version: None,
+            });
+        }
+    }
+
+    diff
+}
+
+#[tauri::command]
+pub async fn install_package(
+    project_path: String,
+    package: String,
+) -> Result<UvOperationResult, String> {
+    // Check if project has pyproject.toml (UV project)
+    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
+    if !pyproject_path.exists() {
+        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
+    }
+
+    let lock_before = read_lockfile(&project_path);
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(&["add", &package])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    if output.status.success() {
+        let lock_after = read_lockfile(&project_path);
+        Ok(UvOperationResult {
+            output: String::from_utf8_lossy(&output.stdout).to_string(),
+            lock_diff: diff_lockfiles(lock_before.as_deref(), lock_after.as_deref()),
+        })
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+// Add several packages in a single `uv add`, resolving them together
+#[tauri::command]
+pub async fn install_packages(
+    project_path: String,
+    packages: Vec<String>,
+) -> Result<BulkInstallResult, String> {
+    let pyproject_path = Path::new(&project_path).join("pyproject.toml");
+    if !pyproject_path.exists() {
+        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
+    }
+
+    let packages: Vec<String> = packages
+        .iter()
+        .map(|package| package.trim().to_string())
+        .filter(|package| !package.is_empty())
+        .collect();
+    if packages.is_empty() {
+        return Err("No packages to install".to_string());
+    }
+    if let Some(option) = packages.iter().find(|package| package.starts_with('-')) {
+        return Err(format!("Invalid package name: {}", option));
+    }
+
+    let lock_before = read_lockfile(&project_path);
+    let mut cmd = uv_project_command(&project_path);
+    cmd.arg("add")
+        .args(&packages)
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let combined = format!("{}{}", stdout, stderr);
+
+    if output.status.success() {
+        let lock_after = read_lockfile(&project_path);
+        Ok(BulkInstallResult {
+            installed: packages,
+            failed: Vec::new(),
+            output: combined,
+            batch_failed: false,
+            lock_diff: diff_lockfiles(lock_before.as_deref(), lock_after.as_deref()),
+        })
+    } else {
+        // Nothing was added; the UI can retry the packages one at a time
+        Ok(BulkInstallResult {
+            installed: Vec::new(),
+            failed: packages,
+            output: combined,
+            batch_failed: true,
+            lock_diff: LockDiff::default(),
+        })
+    }
+}
+
+#[tauri::command]
+pub async fn uninstall_package(
+    project_path: String,
+    package: String,
+) -> Result<UvOperationResult, String> {
+    // Check if project has pyproject.toml (UV project)
+    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
+    if !pyproject_path.exists() {
+        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
+    }
+
+    let lock_before = read_lockfile(&project_path);
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(&["remove", &package])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    if output.status.success() {
+        let lock_after = read_lockfile(&project_path);
+        Ok(UvOperationResult {
+            output: String::from_utf8_lossy(&output.stdout).to_string(),
+            lock_diff: diff_lockfiles(lock_before.as_deref(), lock_after.as_deref()),
+        })
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn import_requirements_to_pyproject(
+    project_path: String,
+) -> Result<RequirementsImportResult, String> {
+    let project_dir = Path::new(&project_path);
+    let requirements_path = project_dir.join("requirements.txt");
+    if !requirements_path.exists() {
+        return Err("requirements.txt not found".to_string());
+    }
+
+    let content = std::fs::read_to_string(&requirements_path)
+        .map_err(|e| format!("Failed to read requirements.txt: {}", e))?;
+
+    let mut warnings = Vec::new();
+    let requirements = parse_requirements(&content, &mut warnings);
+
+    // uv add needs a pyproject.toml to write into
+    if !project_dir.join("pyproject.toml").exists() {
+        let mut cmd = uv_command();
+        cmd.args(["init", "--bare"])
+            .current_dir(&project_path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let output = cmd.output()
+            .map_err(|e| format!("Failed to execute uv init: {}", e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+    }
+
+    let mut added = Vec::new();
+    let mut failed = Vec::new();
+
+    for requirement in requirements {
+        let mut cmd = uv_project_command(&project_path);
+        cmd.args(["add", &requirement])
+            .current_dir(&project_path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        match cmd.output() {
+            Ok(output) if output.status.success() => added.push(requirement),
+            Ok(output) => failed.push(FailedRequirement {
+                requirement,
+                error: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }),
+            Err(e) => failed.push(FailedRequirement {
+                requirement,
+                error: format!("Failed to execute uv: {}", e),
+            }),
+        }
+    }
+
+    Ok(RequirementsImportResult {
+        added,
+        failed,
+        warnings,
+    })
+}
+
+// Extract installable requirement specifiers from a requirements.txt, skipping
+// comments, nested includes and editable installs
+pub(crate) fn parse_requirements(content: &str, warnings: &mut Vec<String>) -> Vec<String> {
+    let mut requirements = Vec::new();
+    let mut pending = String::new();
+
+    for raw_line in content.lines() {
+        // Join backslash line continuations
+        if let Some(stripped) = raw_line.trim_end().strip_suffix('\\') {
+            pending.push_str(stripped);
+            pending.push(' ');
+            continue;
+        }
+        pending.push_str(raw_line);
+        let line = std::mem::take(&mut pending);
+
+        // Inline comments must be preceded by whitespace
+        let line = match line.find(" #") {
+            Some(pos) => &line[..pos],
+            None => line.as_str(),
+        };
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with("-r") || line.starts_with("--requirement") {
+            warnings.push(format!("Skipped nested requirements file: {}", line));
+        } else if line.starts_with("-e") || line.starts_with("--editable") {
+            warnings.push(format!("Skipped editable install: {}", line));
+        } else if line.starts_with('-') {
+            warnings.push(format!("Skipped unsupported option: {}", line));
+        } else {
+            requirements.push(line.to_string());
+        }
+    }
+
+    requirements
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct VersionMismatch {
+    pub package: String,
+    pub required: Option<String>,
+    pub installed: Option<String>,
+    // "Match", "Mismatch", "Missing" or "Extra"
+    pub status: String,
+}
+
+// PEP 503 name normalization so `Foo_Bar` and `foo-bar` compare equal
+fn normalize_package_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.trim().chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.extend(c.to_lowercase());
+        }
+    }
+    normalized
+}
+
+// Split a requirement into its normalized name and version specifier,
+// ignoring extras and environment markers
+fn split_requirement(requirement: &str) -> (String, Option<String>) {
+    let requirement = requirement.split(';').next().unwrap_or("").trim();
+    let name_end = requirement
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    let name = normalize_package_name(&requirement[..name_end]);
+
+    let mut rest = requirement[name_end..].trim_start();
+    if rest.starts_with('[') {
+        rest = match rest.find(']') {
+            Some(end) => rest[end + 1..].trim_start(),
+            None => "",
+        };
+    }
+    let spec = rest.trim();
+    let spec = if spec.is_empty() {
+        None
+    } else if spec.starts_with('@') {
+        // Direct references keep their URL as written
+        Some(spec.to_string())
+    } else {
+        Some(spec.replace(' ', ""))
+    };
+    (name, spec)
+}
+
+// `==1.0` matches 1.0.0 and `==1.2.*` matches any 1.2 release
+fn pin_matches(installed: &str, pinned: &str) -> bool {
+    if let Some(prefix) = pinned.strip_suffix(".*") {
+        return installed == prefix || installed.starts_with(&format!("{}.", prefix));
+    }
+    let trim = |version: &str| {
+        let mut parts: Vec<&str> = version.split('.').collect();
+        while parts.len() > 1 && parts.last() == Some(&"0") {
+            parts.pop();
+        }
+        parts.join(".")
+    };
+    trim(installed) == trim(pinned)
+}
+
+// Installed packages from `uv pip freeze`, keyed by normalized name
+fn parse_freeze(output: &str) -> BTreeMap<String, String> {
+    let mut installed = BTreeMap::new();
+    for line in output.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('-') {
+            continue;
+        }
+        if let Some((name, version)) = line.split_once("==") {
+            installed.insert(normalize_package_name(name), version.trim().to_string());
+        } else if let Some((name, source)) = line.split_once(" @ ") {
+            installed.insert(normalize_package_name(name), source.trim().to_string());
+        }
+    }
+    installed
+}
+
+// Compare a requirements file against the project's venv. Only `==` pins
+// are checked against the installed version; other requirements match as
+// long as the package is installed.
+#[tauri::command]
+pub async fn diff_against_requirements(
+    project_path: String,
+    requirements_path: String,
+) -> Result<Vec<VersionMismatch>, String> {
+    let requirements_file = Path::new(&project_path).join(&requirements_path);
+    let content = std::fs::read_to_string(&requirements_file)
+        .map_err(|e| format!("Failed to read {}: {}", requirements_file.display(), e))?;
+    let mut warnings = Vec::new();
+    let requirements = parse_requirements(&content, &mut warnings);
+
+    let python = venv_python(&project_path);
+    if !Path::new(&python).exists() {
+        return Err("Virtual environment not found. Please create one first.".to_string());
+    }
+
+    let mut cmd = uv_command();
+    cmd.args(["pip", "freeze", "--python", &python])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    let mut installed = parse_freeze(&String::from_utf8_lossy(&output.stdout));
+
+    let mut results = Vec::new();
+    let mut seen = HashSet::new();
+    for requirement in requirements {
+        let (package, spec) = split_requirement(&requirement);
+        if package.is_empty() || !seen.insert(package.clone()) {
+            continue;
+        }
+        let installed_version = installed.remove(&package);
+        let pinned = spec
+            .as_deref()
+            .and_then(|spec| spec.strip_prefix("=="))
+            .filter(|version| !version.contains(','));
+        let status = match (&installed_version, pinned) {
+            (None, _) => "Missing",
+            (Some(version), Some(pinned)) if !pin_matches(version, pinned) => "Mismatch",
+            _ => "Match",
+        };
+        results.push(VersionMismatch {
+            package,
+            required: spec,
+            installed: installed_version,
+            status: status.to_string(),
+        });
+    }
+
+    results.extend(
+        installed
+            .into_iter()
+            .map(|(package, version)| VersionMismatch {
+                package,
+                required: None,
+                installed: Some(version),
+                status: "Extra".to_string(),
+            }),
+    );
+
+    Ok(results)
+}
+
+#[tauri::command]
+pub async fn get_dependency_tree(project_path: String) -> Result<DependencyTree, String> {
+    // Check if project has pyproject.toml (UV project)
+    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
+    if !pyproject_path.exists() {
+        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
+    }
+
+    // Use uv tree to show detailed dependencies
+    let mut cmd = uv_command();
+    cmd.args(&["tree", "--depth", "3"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let nodes = parse_uv_tree(&stdout);
+
+        // Top-level entries are the roots of the tree, each listing everything
+        // beneath it once
+        let mut packages: Vec<PackageWithDeps> = Vec::new();
+        for node in &nodes {
+            if node.depth == 0 {
+                packages.push(PackageWithDeps {
+                    name: node.name.clone(),
+                    version: node.version.clone(),
+                    dependencies: Vec::new(),
+                    depth: 0,
+                });
+            } else if let Some(root) = packages.last_mut() {
+                if !root.dependencies.iter().any(|d| d.name == node.name) {
+                    root.dependencies.push(Package {
+                        name: node.name.clone(),
+                        version: node.version.clone(),
+                    });
+                }
+            }
+        }
+
+        let total_count = packages.len() as u32;
+        Ok(DependencyTree {
+            packages,
+            total_count,
+        })
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+struct TreeNode {
+    name: String,
+    version: String,
+    depth: usize,
+    parent: Option<usize>,
+}
+
+// Parse `uv tree` output into nodes that remember their parent, so transitive
+// dependencies keep their real edges instead of collapsing under the root
+fn parse_uv_tree(output: &str) -> Vec<TreeNode> {
+    let mut nodes: Vec<TreeNode> = Vec::new();
+    // Index of the most recent node seen at each depth
+    let mut ancestors: Vec<usize> = Vec::new();
+
+    for line in output.lines() {
+        let prefix_len = line
+            .chars()
+            .take_while(|c| matches!(c, '│' | '├' | '└' | '─' | ' '))
+            .count();
+        let depth = prefix_len / 4;
+
+        let cleaned_line = line.chars().skip(prefix_len).collect::<String>();
+        let version_pos = match cleaned_line.find(" v") {
+            Some(pos) => pos,
+            None => continue,
+        };
+
+        // Drop extras from the name and markers like "(*)" or "(extra: x)"
+        // after the version
+        let name = cleaned_line[..version_pos].trim();
+        let name = name.split('[').next().unwrap_or(name).to_string();
+        let version = cleaned_line[version_pos + 2..]
+            .split_whitespace()
+            .next()
+            .unwrap_or("")
+            .to_string();
+        if name.is_empty() || version.is_empty() {
+            continue;
+        }
+
+        ancestors.truncate(depth);
+        let parent = if depth > 0 { ancestors.last().copied() } else { None };
+
+        nodes.push(TreeNode {
+            name,
+            version,
+            depth,
+            parent,
+        });
+        ancestors.push(nodes.len() - 1);
+    }
+
+    nodes
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[tauri::command]
+pub async fn get_dependency_graph_dot(project_path: String) -> Result<String, String> {
+    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
+    if !pyproject_path.exists() {
+        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
+    }
+
+    let mut cmd = uv_command();
+    cmd.args(["tree"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let nodes = parse_uv_tree(&stdout);
+
+    let mut dot = String::from("digraph dependencies {\n    rankdir=LR;\n    node [shape=box];\n");
+
+    // Shared packages appear several times in the tree but only once in the graph
+    let mut seen_nodes = HashSet::new();
+    for node in &nodes {
+        if seen_nodes.insert(node.name.as_str()) {
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\\nv{}\"];\n",
+                dot_escape(&node.name),
+                dot_escape(&node.name),
+                dot_escape(&node.version)
+            ));
+        }
+    }
+
+    let mut seen_edges = HashSet::new();
+    for node in &nodes {
+        if let Some(parent) = node.parent {
+            let from = nodes[parent].name.as_str();
+            if seen_edges.insert((from, node.name.as_str())) {
+                dot.push_str(&format!(
+                    "    \"{}\" -> \"{}\";\n",
+                    dot_escape(from),
+                    dot_escape(&node.name)
+                ));
+            }
+        }
+    }
+
+    dot.push_str("}\n");
+    Ok(dot)
+}
+
+#[tauri::command]
+pub async fn list_packages(project_path: String) -> Result<Vec<Package>, String> {
+    // Check if project has pyproject.toml (UV project)
+    let pyproject_path = std::path::Path::new(&project_path).join("pyproject.toml");
+    if !pyproject_path.exists() {
+        return Err("This is not a UV project. Please initialize with 'uv init' first or create a pyproject.toml file.".to_string());
+    }
+
+    // Use uv tree to show dependencies
+    let mut cmd = uv_command();
+    cmd.args(&["tree"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
 
     if output.status.success() {
         let stdout = String::from_utf8_lossy(&output.stdout);
@@ -433,13 +1683,137 @@ pub async fn list_packages(project_path: String) -> Result<Vec<Package>, String>
     }
 }
 
+// site-packages of the active venv: Lib/site-packages on Windows,
+// lib/pythonX.Y/site-packages elsewhere
+fn site_packages_dir(project_path: &str) -> Option<PathBuf> {
+    let venv = PathBuf::from(venv_dir(project_path));
+    let windows_layout = venv.join("Lib").join("site-packages");
+    if windows_layout.is_dir() {
+        return Some(windows_layout);
+    }
+
+    std::fs::read_dir(venv.join("lib"))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path().join("site-packages"))
+        .find(|path| path.is_dir())
+}
+
+// Header fields of a dist-info METADATA file, joining continuation lines.
+// The headers end at the first blank line, where the long description starts.
+fn parse_metadata_headers(metadata: &str) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in metadata.lines() {
+        if line.trim().is_empty() {
+            break;
+        }
+        if line.starts_with(' ') || line.starts_with('\t') {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push('\n');
+                value.push_str(line.trim());
+            }
+        } else if let Some((key, value)) = line.split_once(':') {
+            headers.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    headers
+}
+
+fn license_from_metadata(headers: &[(String, String)]) -> Option<String> {
+    let field = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, value)| key.eq_ignore_ascii_case(name) && !value.is_empty())
+            .map(|(_, value)| value.clone())
+    };
+
+    // PEP 639 SPDX expression first
+    if let Some(expression) = field("License-Expression") {
+        return Some(expression);
+    }
+
+    // Some packages paste the whole license text into `License`; prefer the
+    // classifiers then
+    let classifiers: Vec<&str> = headers
+        .iter()
+        .filter(|(key, _)| key.eq_ignore_ascii_case("Classifier"))
+        .filter_map(|(_, value)| value.strip_prefix("License ::"))
+        .filter_map(|value| value.rsplit("::").next())
+        .map(str::trim)
+        .filter(|value| *value != "OSI Approved")
+        .collect();
+
+    match field("License") {
+        Some(license) if !license.contains('\n') && license.len() <= 100 && license != "UNKNOWN" => {
+            Some(license)
+        }
+        license => {
+            if !classifiers.is_empty() {
+                Some(classifiers.join(", "))
+            } else {
+                license
+                    .filter(|l| l != "UNKNOWN")
+                    .and_then(|l| l.lines().next().map(|line| line.to_string()))
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_dependency_licenses(project_path: String) -> Result<Vec<PackageLicense>, String> {
+    let site_packages = site_packages_dir(&project_path)
+        .ok_or("No site-packages found. Create the virtual environment and install dependencies first.")?;
+
+    let entries = std::fs::read_dir(&site_packages)
+        .map_err(|e| format!("Failed to read site-packages: {}", e))?;
+
+    let mut licenses = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_dist_info = path
+            .extension()
+            .map_or(false, |ext| ext == "dist-info");
+        if !is_dist_info {
+            continue;
+        }
+
+        let metadata = match std::fs::read_to_string(path.join("METADATA")) {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+        let headers = parse_metadata_headers(&metadata);
+        let field = |name: &str| {
+            headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default()
+        };
+
+        let license = license_from_metadata(&headers);
+        let flagged = match &license {
+            Some(license) => {
+                let upper = license.to_uppercase();
+                upper.contains("GPL") || upper.contains("GENERAL PUBLIC LICENSE")
+            }
+            None => true,
+        };
+
+        licenses.push(PackageLicense {
+            name: field("Name"),
+            version: field("Version"),
+            license: license.unwrap_or_else(|| "Unknown".to_string()),
+            flagged,
+        });
+    }
+
+    licenses.sort_by_key(|l| l.name.to_lowercase());
+    Ok(licenses)
+}
+
 #[tauri::command]
 pub async fn run_script(project_path: String, script_path: String) -> Result<String, String> {
-    let python_exe = if cfg!(target_os = "windows") {
-        format!("{}/.venv/Scripts/python.exe", project_path)
-    } else {
-        format!("{}/.venv/bin/python", project_path)
-    };
+    let python_exe = venv_python(&project_path);
 
     let mut cmd = Command::new(python_exe);
     cmd.arg(script_path)
@@ -460,131 +1834,304 @@ pub async fn run_script(project_path: String, script_path: String) -> Result<Str
     Ok(format!("{}{}", stdout, stderr))
 }
 
+// Like `run_script`, but a crash comes back with its traceback parsed into
+// frames the editor can link to
 #[tauri::command]
-pub async fn run_script_with_output_streaming(
-    window: Window,
+pub async fn run_script_with_traceback(
     project_path: String,
     script_path: String,
-    process_manager: State<'_, ProcessManager>,
-) -> Result<String, String> {
-    // Kill any existing process first
+) -> Result<TracebackRunResult, String> {
+    let python_exe = venv_python(&project_path);
+
+    let mut cmd = Command::new(python_exe);
+    cmd.arg(script_path)
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(target_os = "windows")]
     {
-        let mut current_process = process_manager.lock().await;
-        if let Some(mut child) = current_process.take() {
-            let _ = child.kill();
-            let _ = child.wait();
-        }
+        cmd.creation_flags(0x08000000);
     }
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute Python script: {}", e))?;
 
-    // Determine Python executable path
-    let python_exe = if cfg!(target_os = "windows") {
-        format!("{}/.venv/Scripts/python.exe", project_path)
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let mut exceptions = if output.status.success() {
+        Vec::new()
     } else {
-        format!("{}/.venv/bin/python", project_path)
+        parse_tracebacks(&stderr)
     };
+    let exception = exceptions.pop();
+
+    Ok(TracebackRunResult {
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr,
+        exit_code: output.status.code(),
+        exception,
+        chained: exceptions,
+    })
+}
 
-    // First, try the virtual environment Python, fallback to system Python
-    let mut cmd = Command::new(&python_exe);
+// `  File "path", line 3, in func`; syntax errors leave out the function
+fn parse_traceback_location(line: &str) -> Option<TracebackFrame> {
+    let rest = line.trim_start().strip_prefix("File \"")?;
+    let (file, rest) = rest.split_once("\", line ")?;
+    let (line_number, function) = match rest.split_once(", in ") {
+        Some((line_number, function)) => (line_number, function),
+        None => (rest, ""),
+    };
+    Some(TracebackFrame {
+        file: file.to_string(),
+        line: line_number.trim().parse().ok()?,
+        function: function.trim().to_string(),
+        code: None,
+    })
+}
 
-    // If venv doesn't exist, try system python
-    if !Path::new(&python_exe).exists() {
-        cmd = Command::new("python");
-    }
+// `ValueError: message`, `module.CustomError: message` or a bare `KeyboardInterrupt`
+fn parse_exception_line(line: &str) -> Option<(String, String)> {
+    let (name, message) = match line.split_once(':') {
+        Some((name, message)) => (name, message.trim_start()),
+        None => (line.trim_end(), ""),
+    };
+    let valid_name = name
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_alphabetic() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.');
+    valid_name.then(|| (name.to_string(), message.to_string()))
+}
 
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
+// Every exception printed in the output, in the order Python prints them, so
+// with chained exceptions the one that ended the script comes last
+fn parse_tracebacks(stderr: &str) -> Vec<ExceptionTrace> {
+    let mut exceptions: Vec<ExceptionTrace> = Vec::new();
+    let mut frames: Vec<TracebackFrame> = Vec::new();
+    // Exception messages can span lines until a blank line
+    let mut in_message = false;
+
+    for line in stderr.lines() {
+        if line.starts_with("Traceback (most recent call last)") {
+            frames.clear();
+            in_message = false;
+        } else if let Some(frame) = parse_traceback_location(line).filter(|_| line.starts_with(' '))
+        {
+            frames.push(frame);
+            in_message = false;
+        } else if line.starts_with(' ') {
+            // Source line under a frame; skip the ^~~~ markers and notes like
+            // "[Previous line repeated 996 more times]"
+            let code = line.trim();
+            let is_marker = code.chars().all(|c| matches!(c, '^' | '~' | ' '));
+            if let Some(frame) = frames.last_mut() {
+                if frame.code.is_none() && !is_marker && !code.starts_with('[') {
+                    frame.code = Some(code.to_string());
+                }
+            }
+        } else if line.trim().is_empty()
+            || line.starts_with("During handling of the above exception")
+            || line.starts_with("The above exception was the direct cause")
+        {
+            in_message = false;
+        } else if in_message {
+            if let Some(exception) = exceptions.last_mut() {
+                exception.message.push('\n');
+                exception.message.push_str(line);
+            }
+        } else if !frames.is_empty() {
+            if let Some((exception_type, message)) = parse_exception_line(line) {
+                exceptions.push(ExceptionTrace {
+                    exception_type,
+                    message,
+                    frames: std::mem::take(&mut frames),
+                });
+                in_message = true;
+            }
+        }
     }
-    let mut child = cmd
-        .arg(&script_path)
-        .current_dir(&project_path)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .stdin(Stdio::null())
-        .spawn()
-        .map_err(|e| format!("Failed to start Python script: {}", e))?;
 
-    let stdout = child.stdout.take().unwrap();
-    let stderr = child.stderr.take().unwrap();
-
-    // Store the process in the manager
-    {
-        let mut current_process = process_manager.lock().await;
-        *current_process = Some(child);
-    }
+    exceptions
+}
 
-    // Handle stdout in a separate task
-    let window_stdout = window.clone();
-    let stdout_handle = tokio::spawn(async move {
-        let reader = BufReader::new(stdout);
-        for line in reader.lines() {
-            if let Ok(line) = line {
-                let _ = window_stdout.emit("script-output", &format!("{}\n", line));
-            }
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn run_script_with_output_streaming(
+    window: Window,
+    project_path: String,
+    script_path: String,
+    log_file: Option<String>,
+    timestamp: Option<bool>,
+    raw_output: Option<bool>,
+    parse_log_levels: Option<bool>,
+    process_manager: State<'_, ProcessManager>,
+) -> Result<String, String> {
+    // Use the virtual environment Python, falling back to the system Python
+    let python_exe = venv_python(&project_path);
+    let mut cmd = if Path::new(&python_exe).exists() {
+        Command::new(&python_exe)
+    } else {
+        Command::new("python")
+    };
+    cmd.arg(&script_path).current_dir(&project_path);
+
+    // Optionally mirror every output line into a log file
+    let log = match log_file {
+        Some(ref log_path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(log_path)
+                .map_err(|e| format!("Failed to open log file {}: {}", log_path, e))?;
+            Some(Arc::new(StdMutex::new(file)))
         }
-    });
+        None => None,
+    };
 
-    // Handle stderr in a separate task
-    let window_stderr = window.clone();
-    let stderr_handle = tokio::spawn(async move {
-        let reader = BufReader::new(stderr);
-        for line in reader.lines() {
-            if let Ok(line) = line {
-                let _ = window_stderr.emit("script-error", &format!("{}\n", line));
+    let options = StreamOptions {
+        log,
+        timestamp: timestamp.unwrap_or(false),
+        raw_output: raw_output.unwrap_or(false),
+        parse_log_levels: parse_log_levels.unwrap_or(false),
+        ..StreamOptions::default()
+    };
+    spawn_streaming_process(window, cmd, &process_manager, options).await?;
+
+    // Return immediately so UI stays responsive
+    Ok("Script started successfully".to_string())
+}
+
+// Append a single output line to the script log, flushing so the file stays
+// current while long-running scripts are still going
+fn append_log_line(log: &StdMutex<File>, line: &str, timestamp: bool) {
+    if let Ok(mut file) = log.lock() {
+        let result = if timestamp {
+            writeln!(
+                file,
+                "[{}] {}",
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+                line
+            )
+        } else {
+            writeln!(file, "{}", line)
+        };
+        if result.is_ok() {
+            let _ = file.flush();
+        }
+    }
+}
+
+// Emit output as soon as it is read instead of line by line, so progress bars
+// that redraw with `\r` update live. Emitted as `script-raw-output` chunks;
+// a log file receives the same decoded text, with invalid UTF-8 replaced.
+// Log levels are parsed from the complete lines as they come together.
+fn forward_raw_output(window: &Window, reader: impl Read, stream: &str, options: &StreamOptions) {
+    // Output after the last newline, waiting for the rest of its line
+    let mut partial = String::new();
+    read_utf8_chunks(reader, |data| {
+        if let Some(ref log) = options.log {
+            if let Ok(mut file) = log.lock() {
+                let _ = file.write_all(data.as_bytes()).and_then(|_| file.flush());
             }
         }
+        if options.parse_log_levels {
+            partial.push_str(&data);
+        }
+        let _ = window.emit(
+            "script-raw-output",
+            RawOutputChunk {
+                stream: stream.to_string(),
+                data,
+            },
+        );
+
+        while let Some(end) = partial.find('\n') {
+            let line: String = partial.drain(..=end).collect();
+            emit_raw_log_entry(window, &line, stream);
+        }
     });
+    if !partial.is_empty() {
+        emit_raw_log_entry(window, &partial, stream);
+    }
+}
 
-    // Handle process completion in background task
-    let process_manager_wait = Arc::clone(&*process_manager);
-    let completion_window = window.clone();
-    tokio::spawn(async move {
-        loop {
-            // Check if process still exists and wait for a short time
-            let should_continue = {
-                let mut current_process = process_manager_wait.lock().await;
-                if let Some(ref mut child) = *current_process {
-                    // Try to wait without blocking indefinitely
-                    match child.try_wait() {
-                        Ok(Some(status)) => {
-                            // Process has completed
-                            *current_process = None;
-                            let _ = completion_window.emit("script-completed", status.success());
-                            break;
-                        }
-                        Ok(None) => {
-                            // Process is still running, continue loop
-                            true
-                        }
-                        Err(_) => {
-                            // Error occurred, consider process stopped
-                            *current_process = None;
-                            let _ = completion_window.emit("script-completed", false);
-                            break;
-                        }
-                    }
-                } else {
-                    // Process was stopped externally
-                    let _ = completion_window.emit("script-completed", false);
-                    break;
+// A line redrawn with `\r` only shows its last version
+fn emit_raw_log_entry(window: &Window, line: &str, stream: &str) {
+    let line = line.trim_end_matches(['\n', '\r']);
+    emit_log_entry(window, line.rsplit('\r').next().unwrap_or(line), stream);
+}
+
+// Normalize a log level name, e.g. "warn" -> "WARNING"
+fn log_level(word: &str) -> Option<String> {
+    let level = match word.trim().to_ascii_uppercase().as_str() {
+        "DEBUG" => "DEBUG",
+        "INFO" => "INFO",
+        "WARNING" | "WARN" => "WARNING",
+        "ERROR" => "ERROR",
+        "CRITICAL" | "FATAL" => "CRITICAL",
+        _ => return None,
+    };
+    Some(level.to_string())
+}
+
+// Recognize the common `logging` formats:
+//   "WARNING:my.module:message"        (basicConfig default)
+//   "WARNING: message"
+//   "... - my.module - WARNING - message"
+//   "[WARNING] message"
+fn parse_log_line(line: &str, stream: &str) -> Option<ScriptLogEntry> {
+    let entry = |level: String, logger: Option<&str>, message: &str| ScriptLogEntry {
+        level,
+        logger: logger
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty()),
+        message: message.trim().to_string(),
+        stream: stream.to_string(),
+    };
+
+    if let Some((level, rest)) = line.trim_start().split_once(':') {
+        if let Some(level) = log_level(level) {
+            return Some(match rest.split_once(':') {
+                Some((logger, message)) if !logger.is_empty() && !logger.contains(' ') => {
+                    entry(level, Some(logger), message)
                 }
-            };
+                // "INFO:     message", as printed by uvicorn
+                _ => entry(level, None, rest),
+            });
+        }
+    }
 
-            if !should_continue {
-                break;
-            }
+    let parts: Vec<&str> = line.split(" - ").collect();
+    if let Some(index) = (1..parts.len().saturating_sub(1)).find(|&i| log_level(parts[i]).is_some())
+    {
+        let level = log_level(parts[index])?;
+        // With only "<time> - LEVEL - message" there is no logger name
+        let logger = if index >= 2 {
+            Some(parts[index - 1])
+        } else {
+            None
+        };
+        return Some(entry(level, logger, &parts[index + 1..].join(" - ")));
+    }
 
-            // Wait a short time before checking again
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let mut rest = line;
+    while let Some(open) = rest.find('[') {
+        let close = rest[open..].find(']')? + open;
+        if let Some(level) = log_level(&rest[open + 1..close]) {
+            return Some(entry(level, None, &rest[close + 1..]));
         }
+        rest = &rest[close + 1..];
+    }
 
-        // Wait for output handlers to complete
-        let _ = stdout_handle.await;
-        let _ = stderr_handle.await;
-    });
+    None
+}
 
-    // Return immediately so UI stays responsive
-    Ok("Script started successfully".to_string())
+fn emit_log_entry(window: &Window, line: &str, stream: &str) {
+    if let Some(entry) = parse_log_line(line, stream) {
+        let _ = window.emit("script-log", entry);
+    }
 }
 
 // Command to stop the currently running process
@@ -596,6 +2143,7 @@ pub async fn stop_running_script(
     let mut current_process = process_manager.lock().await;
     if let Some(mut child) = current_process.take() {
         println!("Found process to kill");
+        // Takes down the whole tree, including python started by `uv run`
         match child.kill() {
             Ok(_) => {
                 println!("Process kill() successful");
@@ -606,36 +2154,104 @@ pub async fn stop_running_script(
             }
         }
 
-        // On Windows, also try to kill UV and Python processes more forcefully
-        #[cfg(target_os = "windows")]
-        {
-            use std::process::Command;
+        Ok("Script stopped successfully".to_string())
+    } else {
+        println!("No process found to kill");
+        Ok("No script is currently running".to_string())
+    }
+}
 
-            // Try to kill any remaining UV or Python processes
-            let mut cmd1 = Command::new("taskkill");
-            cmd1.args(&["/F", "/IM", "uv.exe"])
-                .stdin(Stdio::null())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .creation_flags(0x08000000);
-            let _ = cmd1.output();
+#[derive(Serialize, Deserialize)]
+pub struct ProcessStats {
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    // Only reported where the OS exposes per-process threads (Linux)
+    pub num_threads: Option<u32>,
+}
 
-            let mut cmd2 = Command::new("taskkill");
-            cmd2.args(&["/F", "/IM", "python.exe"])
-                .stdin(Stdio::null())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .creation_flags(0x08000000);
-            let _ = cmd2.output();
+// The tracked process plus everything it started, since the script itself
+// runs as a child of `uv run`
+fn process_tree_pids(system: &sysinfo::System, root: sysinfo::Pid) -> Vec<sysinfo::Pid> {
+    let mut children: HashMap<sysinfo::Pid, Vec<sysinfo::Pid>> = HashMap::new();
+    for (pid, process) in system.processes() {
+        // On Linux threads are listed as processes too
+        if process.thread_kind().is_some() {
+            continue;
+        }
+        if let Some(parent) = process.parent() {
+            children.entry(parent).or_default().push(*pid);
+        }
+    }
 
-            println!("Attempted forceful termination of UV and Python processes");
+    let mut pids = vec![root];
+    let mut index = 0;
+    while index < pids.len() {
+        if let Some(descendants) = children.get(&pids[index]) {
+            pids.extend(descendants.iter().copied());
         }
+        index += 1;
+    }
+    pids
+}
 
-        Ok("Script stopped successfully".to_string())
-    } else {
-        println!("No process found to kill");
-        Ok("No script is currently running".to_string())
+// Current resource usage of the tracked script, for a live meter in the UI
+#[tauri::command]
+pub async fn get_process_stats(
+    process_id: u32,
+    process_manager: State<'_, ProcessManager>,
+) -> Result<ProcessStats, String> {
+    let is_tracked = |process_manager: &mut Option<ProcessTree>| {
+        process_manager
+            .as_mut()
+            .filter(|child| child.id() == process_id)
+            .map_or(false, |child| matches!(child.try_wait(), Ok(None)))
+    };
+    if !is_tracked(&mut *process_manager.lock().await) {
+        return Err(format!("Process {} is not running", process_id));
+    }
+
+    // CPU usage is measured between two refreshes
+    let refresh_kind = sysinfo::ProcessRefreshKind::new().with_cpu().with_memory();
+    let mut system = sysinfo::System::new();
+    system.refresh_processes_specifics(refresh_kind);
+    tokio::time::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL).await;
+    system.refresh_processes_specifics(refresh_kind);
+
+    let root = sysinfo::Pid::from_u32(process_id);
+    if system.process(root).is_none() || !is_tracked(&mut *process_manager.lock().await) {
+        return Err(format!("Process {} is not running", process_id));
+    }
+
+    let mut stats = ProcessStats {
+        cpu_percent: 0.0,
+        memory_bytes: 0,
+        num_threads: Some(0),
+    };
+    for pid in process_tree_pids(&system, root) {
+        if let Some(process) = system.process(pid) {
+            stats.cpu_percent += process.cpu_usage();
+            stats.memory_bytes += process.memory();
+            // `tasks` lists the threads besides the main one
+            stats.num_threads = match (stats.num_threads, process.tasks()) {
+                (Some(total), Some(tasks)) => Some(total + tasks.len() as u32 + 1),
+                _ => None,
+            };
+        }
     }
+
+    Ok(stats)
+}
+
+// Id of the script currently tracked by the process manager, if any
+#[tauri::command]
+pub async fn get_running_process_id(
+    process_manager: State<'_, ProcessManager>,
+) -> Result<Option<u32>, String> {
+    Ok(process_manager
+        .lock()
+        .await
+        .as_ref()
+        .map(|child| child.id()))
 }
 
 // Simplified version for quick execution without streaming
@@ -644,11 +2260,7 @@ pub async fn run_script_simple(
     project_path: String,
     script_path: String,
 ) -> Result<String, String> {
-    let python_exe = if cfg!(target_os = "windows") {
-        format!("{}/.venv/Scripts/python.exe", project_path)
-    } else {
-        format!("{}/.venv/bin/python", project_path)
-    };
+    let python_exe = venv_python(&project_path);
 
     // First, try the virtual environment Python, fallback to system Python
     let mut cmd = Command::new(&python_exe);
@@ -663,101 +2275,814 @@ pub async fn run_script_simple(
         cmd.creation_flags(0x08000000);
     }
     let output = cmd
-        .arg(script_path)
+        .arg(script_path)
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to execute Python script: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    Ok(format!("{}{}", stdout, stderr))
+}
+
+// Dumps a cProfile stats file as JSON, sorted by cumulative time
+const PSTATS_TO_JSON: &str = r#"
+import json, pstats, sys
+stats = pstats.Stats(sys.argv[1])
+rows = []
+for (filename, line, name), (cc, nc, tt, ct, callers) in stats.stats.items():
+    rows.append({"function": name, "file": filename, "line": line, "calls": nc, "total_time": tt, "cumulative_time": ct})
+rows.sort(key=lambda r: r["cumulative_time"], reverse=True)
+print(json.dumps(rows[:int(sys.argv[2])]))
+"#;
+
+#[tauri::command]
+pub async fn profile_script(
+    project_path: String,
+    script_path: String,
+    limit: Option<u32>,
+) -> Result<Vec<ProfileEntry>, String> {
+    let limit = limit.unwrap_or(50);
+    let profile_path = std::env::temp_dir().join(format!(
+        "pyra-profile-{}-{}.out",
+        std::process::id(),
+        chrono::Utc::now().timestamp_millis()
+    ));
+    let profile_arg = profile_path.to_string_lossy().to_string();
+
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "python", "-m", "cProfile", "-o", &profile_arg, &script_path])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv run: {}", e))?;
+
+    // The profile is written even if the script exits with an error
+    if !profile_path.exists() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "python", "-c", PSTATS_TO_JSON, &profile_arg, &limit.to_string()])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let stats_output = cmd.output();
+    let _ = std::fs::remove_file(&profile_path);
+    let stats_output = stats_output
+        .map_err(|e| format!("Failed to execute uv run: {}", e))?;
+
+    if !stats_output.status.success() {
+        return Err(String::from_utf8_lossy(&stats_output.stderr).to_string());
+    }
+
+    serde_json::from_slice(&stats_output.stdout)
+        .map_err(|e| format!("Failed to parse profile stats: {}", e))
+}
+
+#[tauri::command]
+pub async fn init_uv_project(
+    project_path: String,
+    project_name: String,
+    python_version: Option<String>,
+) -> Result<String, String> {
+    // Initialize UV project with pyproject.toml
+    let mut args = vec!["init", "--name", &project_name];
+
+    // Add python version if specified
+    if let Some(ref version) = python_version {
+        args.push("--python");
+        args.push(version);
+    }
+
+    let mut cmd = uv_command();
+    cmd.args(&args)
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv init: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn sync_uv_project(project_path: String) -> Result<UvOperationResult, String> {
+    // Sync dependencies based on pyproject.toml and uv.lock
+    let lock_before = read_lockfile(&project_path);
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(&["sync"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv sync: {}", e))?;
+
+    if output.status.success() {
+        let lock_after = read_lockfile(&project_path);
+        Ok(UvOperationResult {
+            output: String::from_utf8_lossy(&output.stdout).to_string(),
+            lock_diff: diff_lockfiles(lock_before.as_deref(), lock_after.as_deref()),
+        })
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SyncAction {
+    // "install", "remove" or "update"
+    pub action: String,
+    pub package: String,
+    // The version after the sync; for a removal, the version removed
+    pub version: String,
+}
+
+// Parse the package lines of `uv sync --dry-run`, e.g. " + requests==2.31.0".
+// A package that is both removed and installed is an update.
+fn parse_sync_plan(output: &str) -> Vec<SyncAction> {
+    let mut removed: Vec<(String, String)> = Vec::new();
+    let mut installed: Vec<(String, String)> = Vec::new();
+
+    for line in output.lines() {
+        let line = line.trim();
+        let (sign, spec) = match line.split_once(' ') {
+            Some((sign @ ("+" | "-" | "~"), spec)) => (sign, spec.trim()),
+            _ => continue,
+        };
+        // Drop a trailing source such as "(from file:///...)"
+        let spec = spec.split(" (").next().unwrap_or(spec);
+        let (package, version) = match spec.split_once("==") {
+            Some((package, version)) => (package.trim(), version.trim()),
+            None => match spec.split_once(" @ ") {
+                Some((package, source)) => (package.trim(), source.trim()),
+                None => continue,
+            },
+        };
+        let entry = (package.to_string(), version.to_string());
+        match sign {
+            "-" => removed.push(entry),
+            // "~" marks a reinstall of the same version
+            _ => installed.push(entry),
+        }
+    }
+
+    let mut actions = Vec::new();
+    for (package, version) in installed {
+        let action = match removed.iter().position(|(name, _)| name == &package) {
+            Some(index) => {
+                removed.remove(index);
+                "update"
+            }
+            None => "install",
+        };
+        actions.push(SyncAction {
+            action: action.to_string(),
+            package,
+            version,
+        });
+    }
+    actions.extend(removed.into_iter().map(|(package, version)| SyncAction {
+        action: "remove".to_string(),
+        package,
+        version,
+    }));
+    actions
+}
+
+// What `uv sync` would change, without changing anything
+#[tauri::command]
+pub async fn sync_dry_run(project_path: String) -> Result<Vec<SyncAction>, String> {
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["sync", "--dry-run"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to execute uv sync: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    // uv reports the plan on stderr
+    let report = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(parse_sync_plan(&report))
+}
+
+#[tauri::command]
+pub async fn run_script_with_uv(
+    project_path: String,
+    script_path: String,
+) -> Result<String, String> {
+    // Use 'uv run' to execute script with project dependencies
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(&["run", "python", &script_path])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv run: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    Ok(format!("{}{}", stdout, stderr))
+}
+
+const DEFAULT_SNIPPET_TIMEOUT_SECS: u64 = 30;
+
+#[tauri::command]
+pub async fn eval_snippet(
+    project_path: String,
+    code: String,
+    timeout_secs: Option<u64>,
+) -> Result<SnippetResult, String> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let snippet_path = std::env::temp_dir().join(format!(
+        "pyra_snippet_{}_{}.py",
+        std::process::id(),
+        nanos
+    ));
+    std::fs::write(&snippet_path, &code)
+        .map_err(|e| format!("Failed to write snippet: {}", e))?;
+
+    let timeout_secs = timeout_secs.unwrap_or(DEFAULT_SNIPPET_TIMEOUT_SECS);
+    let result = run_snippet_file(&project_path, &snippet_path, timeout_secs).await;
+    let _ = std::fs::remove_file(&snippet_path);
+    result
+}
+
+async fn run_snippet_file(
+    project_path: &str,
+    snippet_path: &Path,
+    timeout_secs: u64,
+) -> Result<SnippetResult, String> {
+    // The snippet lives in the temp dir, so put the project on sys.path to
+    // keep its own modules importable
+    let mut python_path = vec![PathBuf::from(project_path)];
+    if let Some(existing) = std::env::var_os("PYTHONPATH") {
+        python_path.extend(std::env::split_paths(&existing));
+    }
+    let python_path = std::env::join_paths(python_path)
+        .map_err(|e| format!("Invalid PYTHONPATH: {}", e))?;
+
+    let mut cmd = tokio::process::Command::from(uv_project_command(project_path));
+    cmd.arg("run")
+        .arg("python")
+        .arg(snippet_path)
+        .current_dir(project_path)
+        .env("PYTHONPATH", python_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    // Own process group, so a timeout can take down python along with uv
+    #[cfg(unix)]
+    cmd.process_group(0);
+    let child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to execute uv run python: {}", e))?;
+    #[cfg(unix)]
+    let pid = child.id();
+
+    match tokio::time::timeout(
+        std::time::Duration::from_secs(timeout_secs),
+        child.wait_with_output(),
+    )
+    .await
+    {
+        Ok(output) => {
+            let output = output.map_err(|e| format!("Failed to run snippet: {}", e))?;
+            Ok(SnippetResult {
+                stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+                stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+                exit_code: output.status.code(),
+                timed_out: false,
+            })
+        }
+        Err(_) => {
+            // uv itself was killed when the child was dropped
+            #[cfg(unix)]
+            if let Some(pid) = pid {
+                unsafe {
+                    libc::kill(-(pid as libc::pid_t), libc::SIGKILL);
+                }
+            }
+            Ok(SnippetResult {
+                stdout: String::new(),
+                stderr: format!("Snippet timed out after {} seconds", timeout_secs),
+                exit_code: None,
+                timed_out: true,
+            })
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BenchmarkResult {
+    pub loops: u32,
+    pub time_per_loop_ns: f64,
+}
+
+// Parse timeit's summary, e.g. "1000 loops, best of 5: 253 nsec per loop"
+fn parse_timeit_output(output: &str) -> Option<BenchmarkResult> {
+    let line = output
+        .lines()
+        .rev()
+        .find(|line| line.contains(" per loop"))?;
+    let (loops, timing) = line.split_once(" loop")?;
+    let timing = timing.rsplit(':').next()?.trim();
+    let mut parts = timing.split_whitespace();
+    let value: f64 = parts.next()?.parse().ok()?;
+    let scale = match parts.next()? {
+        "nsec" => 1.0,
+        "usec" => 1e3,
+        "msec" => 1e6,
+        "sec" => 1e9,
+        _ => return None,
+    };
+    Some(BenchmarkResult {
+        loops: loops.trim().parse().ok()?,
+        time_per_loop_ns: value * scale,
+    })
+}
+
+// Time `statement` with timeit in the project environment, so `setup` can
+// import the project's own modules and dependencies
+#[tauri::command]
+pub async fn benchmark_snippet(
+    project_path: String,
+    setup: String,
+    statement: String,
+    number: u32,
+) -> Result<BenchmarkResult, String> {
+    if number == 0 {
+        return Err("The number of loops must be at least 1".to_string());
+    }
+    if statement.trim().is_empty() {
+        return Err("No statement to benchmark".to_string());
+    }
+
+    let number = number.to_string();
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "python", "-m", "timeit", "-n", &number, "-u", "nsec"]);
+    if !setup.trim().is_empty() {
+        cmd.args(["-s", &setup]);
+    }
+    let output = cmd
+        .args(["--", &statement])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to execute uv run python: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    parse_timeit_output(&stdout)
+        .ok_or_else(|| format!("Failed to parse timeit output: {}", stdout.trim()))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportTiming {
+    pub module: String,
+    pub self_us: u64,
+    pub cumulative_us: u64,
+    // Imports triggered by this one, slowest first
+    pub children: Vec<ImportTiming>,
+}
+
+fn sort_import_timings(timings: &mut [ImportTiming]) {
+    timings.sort_by_key(|timing| std::cmp::Reverse(timing.cumulative_us));
+    for timing in timings.iter_mut() {
+        sort_import_timings(&mut timing.children);
+    }
+}
+
+// Build the import tree from `-X importtime` output on stderr, e.g.
+// "import time:       399 |        466 |   codecs". A module's own imports
+// are printed before it, one level (two spaces) deeper.
+fn parse_importtime(output: &str) -> Vec<ImportTiming> {
+    let mut stack: Vec<(usize, ImportTiming)> = Vec::new();
+
+    for line in output.lines() {
+        let rest = match line.strip_prefix("import time:") {
+            Some(rest) => rest,
+            None => continue,
+        };
+        let mut fields = rest.splitn(3, '|');
+        let (self_us, cumulative_us, name) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(self_us), Some(cumulative_us), Some(name)) => (self_us, cumulative_us, name),
+            _ => continue,
+        };
+        // Skips the header line too
+        let (self_us, cumulative_us) = match (self_us.trim().parse(), cumulative_us.trim().parse())
+        {
+            (Ok(self_us), Ok(cumulative_us)) => (self_us, cumulative_us),
+            _ => continue,
+        };
+        let module = name.trim_start();
+        let depth = (name.len() - module.len()).saturating_sub(1) / 2;
+
+        let mut children = Vec::new();
+        while stack
+            .last()
+            .map_or(false, |(child_depth, _)| *child_depth > depth)
+        {
+            if let Some((_, child)) = stack.pop() {
+                children.push(child);
+            }
+        }
+        children.reverse();
+        stack.push((
+            depth,
+            ImportTiming {
+                module: module.trim_end().to_string(),
+                self_us,
+                cumulative_us,
+                children,
+            },
+        ));
+    }
+
+    let mut timings: Vec<ImportTiming> = stack.into_iter().map(|(_, timing)| timing).collect();
+    // Everything up to `site` is interpreter startup rather than the import
+    // being profiled
+    if let Some(site) = timings.iter().position(|timing| timing.module == "site") {
+        timings.drain(..=site);
+    }
+    sort_import_timings(&mut timings);
+    timings
+}
+
+// Time `import <module>` in the project environment with `-X importtime`
+#[tauri::command]
+pub async fn profile_imports(
+    project_path: String,
+    module: String,
+) -> Result<Vec<ImportTiming>, String> {
+    let module = module.trim();
+    let valid = !module.is_empty()
+        && module.split('.').all(|part| {
+            !part.is_empty()
+                && !part.starts_with(|c: char| c.is_ascii_digit())
+                && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+        });
+    if !valid {
+        return Err(format!("Invalid module name: {}", module));
+    }
+
+    let mut cmd = uv_project_command(&project_path);
+    let output = cmd
+        .args(["run", "python", "-X", "importtime", "-c"])
+        .arg(format!("import {}", module))
         .current_dir(&project_path)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .output()
-        .map_err(|e| format!("Failed to execute Python script: {}", e))?;
+        .map_err(|e| format!("Failed to execute uv run python: {}", e))?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        // Leave out the timings so the ImportError is readable
+        let error: Vec<&str> = stderr
+            .lines()
+            .filter(|line| !line.starts_with("import time:"))
+            .collect();
+        return Err(error.join("\n"));
+    }
+    Ok(parse_importtime(&stderr))
+}
 
-    Ok(format!("{}{}", stdout, stderr))
+#[derive(Serialize, Deserialize)]
+pub struct PythonEnvironmentInfo {
+    pub executable: String,
+    pub version: String,
+    pub sys_path: Vec<String>,
+    pub site_packages: Vec<String>,
+    pub prefix: String,
+    // False when no .venv exists and the info is from the interpreter uv
+    // would fall back to
+    pub venv_exists: bool,
 }
 
+const ENVIRONMENT_INFO_TIMEOUT_SECS: u64 = 20;
+
+const ENVIRONMENT_INFO_SCRIPT: &str = r#"
+import json, site, sys
+try:
+    site_packages = site.getsitepackages()
+except AttributeError:
+    site_packages = []
+print(json.dumps({
+    "executable": sys.executable,
+    "version": sys.version,
+    "sys_path": sys.path,
+    "site_packages": site_packages,
+    "prefix": sys.prefix,
+}))
+"#;
+
+// Where the project's interpreter lives and where it looks for imports
 #[tauri::command]
-pub async fn init_uv_project(
+pub async fn get_python_environment_info(
     project_path: String,
-    project_name: String,
-    python_version: Option<String>,
-) -> Result<String, String> {
-    // Initialize UV project with pyproject.toml
-    let mut args = vec!["init", "--name", &project_name];
-
-    // Add python version if specified
-    if let Some(ref version) = python_version {
-        args.push("--python");
-        args.push(version);
+) -> Result<PythonEnvironmentInfo, String> {
+    let venv_exists = Path::new(&venv_python(&project_path)).exists();
+
+    // Inspect the active venv, which may be a named one rather than `.venv`
+    let mut cmd = tokio::process::Command::from(uv_project_command(&project_path));
+    cmd.arg("run");
+    if !venv_exists {
+        // Don't create and sync a venv just to report on it
+        cmd.arg("--no-project");
     }
-
-    let mut cmd = Command::new("uv");
-    cmd.args(&args)
+    cmd.args(["python", "-c", ENVIRONMENT_INFO_SCRIPT])
         .current_dir(&project_path)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    let child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to execute uv run python: {}", e))?;
+
+    let output = tokio::time::timeout(
+        std::time::Duration::from_secs(ENVIRONMENT_INFO_TIMEOUT_SECS),
+        child.wait_with_output(),
+    )
+    .await
+    .map_err(|_| {
+        format!(
+            "Timed out after {} seconds waiting for the Python environment",
+            ENVIRONMENT_INFO_TIMEOUT_SECS
+        )
+    })?
+    .map_err(|e| format!("Failed to run python: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
     }
-    let output = cmd.output()
-        .map_err(|e| format!("Failed to execute uv init: {}", e))?;
 
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    // uv may print its own messages first, so take the last line
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json = stdout
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or("");
+    let info: serde_json::Value = serde_json::from_str(json)
+        .map_err(|e| format!("Failed to parse environment info: {}", e))?;
+
+    let string = |key: &str| {
+        info.get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string()
+    };
+    let strings = |key: &str| -> Vec<String> {
+        info.get(key)
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|v| v.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    Ok(PythonEnvironmentInfo {
+        executable: string("executable"),
+        version: string("version"),
+        sys_path: strings("sys_path"),
+        site_packages: strings("site_packages"),
+        prefix: string("prefix"),
+        venv_exists,
+    })
+}
+
+const MEMORY_SAMPLE_INTERVAL_MS: u64 = 250;
+
+// Current and peak resident set size of a process, in bytes
+#[cfg(target_os = "linux")]
+fn process_memory(pid: u32) -> Option<(u64, u64)> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let field = |name: &str| -> Option<u64> {
+        let line = status.lines().find(|line| line.starts_with(name))?;
+        let kb: u64 = line[name.len()..].trim().trim_end_matches("kB").trim().parse().ok()?;
+        Some(kb * 1024)
+    };
+    let rss = field("VmRSS:")?;
+    Some((rss, field("VmHWM:").unwrap_or(rss)))
+}
+
+#[cfg(target_os = "windows")]
+fn process_memory(pid: u32) -> Option<(u64, u64)> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+    use windows_sys::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if handle.is_null() {
+            return None;
+        }
+        let mut counters: PROCESS_MEMORY_COUNTERS = std::mem::zeroed();
+        let size = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+        counters.cb = size;
+        let ok = GetProcessMemoryInfo(handle, &mut counters, size);
+        CloseHandle(handle);
+        (ok != 0).then(|| (counters.WorkingSetSize as u64, counters.PeakWorkingSetSize as u64))
     }
 }
 
+// macOS and other Unixes: ps reports RSS in kilobytes
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn process_memory(pid: u32) -> Option<(u64, u64)> {
+    let output = Command::new("ps")
+        .args(["-o", "rss=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    let kb: u64 = String::from_utf8_lossy(&output.stdout).trim().parse().ok()?;
+    Some((kb * 1024, kb * 1024))
+}
+
+// Runs the interpreter directly rather than through `uv run`, so the sampled
+// process is the script itself and not uv
 #[tauri::command]
-pub async fn sync_uv_project(project_path: String) -> Result<String, String> {
-    // Sync dependencies based on pyproject.toml and uv.lock
-    let mut cmd = Command::new("uv");
-    cmd.args(&["sync"])
+pub async fn run_script_with_memory(
+    window: Window,
+    project_path: String,
+    script_path: String,
+    process_manager: State<'_, ProcessManager>,
+) -> Result<MemoryRunResult, String> {
+    // Kill any existing process first
+    {
+        let mut current_process = process_manager.lock().await;
+        if let Some(mut child) = current_process.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+
+    let python_exe = venv_python(&project_path);
+    let mut cmd = if Path::new(&python_exe).exists() {
+        Command::new(&python_exe)
+    } else {
+        Command::new("python")
+    };
+    cmd.arg(&script_path)
         .current_dir(&project_path)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
+    new_process_group(&mut cmd);
     #[cfg(target_os = "windows")]
     {
         cmd.creation_flags(0x08000000);
     }
-    let output = cmd.output()
-        .map_err(|e| format!("Failed to execute uv sync: {}", e))?;
+    let mut child = cmd.spawn()
+        .map_err(|e| format!("Failed to start Python process: {}", e))?;
 
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    let started = std::time::Instant::now();
+    let pid = child.id();
+    let stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
+
+    {
+        let mut current_process = process_manager.lock().await;
+        *current_process = Some(ProcessTree::new(child));
     }
+
+    let window_stdout = window.clone();
+    let stdout_handle = tokio::spawn(async move {
+        forward_output(&window_stdout, stdout, "stdout", &StreamOptions::default());
+    });
+
+    let window_stderr = window.clone();
+    let stderr_handle = tokio::spawn(async move {
+        forward_output(&window_stderr, stderr, "stderr", &StreamOptions::default());
+    });
+
+    let mut peak_memory_bytes = 0;
+    let exit_code = loop {
+        if let Some((rss_bytes, peak)) = process_memory(pid) {
+            peak_memory_bytes = peak_memory_bytes.max(peak).max(rss_bytes);
+            let _ = window.emit(
+                "memory-sample",
+                MemorySample {
+                    elapsed_ms: started.elapsed().as_millis() as u64,
+                    rss_bytes,
+                },
+            );
+        }
+
+        {
+            let mut current_process = process_manager.lock().await;
+            let tracked = current_process.as_mut().filter(|child| child.id() == pid);
+            match tracked.map(|child| child.try_wait()) {
+                Some(Ok(Some(status))) => {
+                    *current_process = None;
+                    break status.code();
+                }
+                Some(Ok(None)) => {}
+                Some(Err(_)) => {
+                    *current_process = None;
+                    break None;
+                }
+                // Stopped externally or replaced by a new run
+                None => break None,
+            }
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(MEMORY_SAMPLE_INTERVAL_MS)).await;
+    };
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    let _ = stdout_handle.await;
+    let _ = stderr_handle.await;
+    let _ = window.emit("script-completed", exit_code == Some(0));
+
+    Ok(MemoryRunResult {
+        peak_memory_bytes,
+        exit_code,
+        duration_ms,
+    })
+}
+
+// Image formats matplotlib and friends typically save plots as
+const FIGURE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "svg", "pdf", "gif"];
+
+fn snapshot_figures(dir: &Path) -> watch::Snapshot {
+    FIGURE_EXTENSIONS
+        .iter()
+        .flat_map(|extension| watch::snapshot_files(dir, extension))
+        .collect()
 }
 
 #[tauri::command]
-pub async fn run_script_with_uv(
+pub async fn run_script_capturing_figures(
     project_path: String,
     script_path: String,
-) -> Result<String, String> {
-    // Use 'uv run' to execute script with project dependencies
-    let mut cmd = Command::new("uv");
-    cmd.args(&["run", "python", &script_path])
+    output_dir: Option<String>,
+) -> Result<FigureRunResult, String> {
+    let output_dir = Path::new(&project_path).join(output_dir.as_deref().unwrap_or("outputs"));
+    let before = snapshot_figures(&output_dir);
+
+    // A non-interactive backend makes plt.show() a no-op instead of opening windows
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "python", &script_path])
+        .env("MPLBACKEND", "Agg")
         .current_dir(&project_path)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv run: {}", e))?;
 
+    let after = snapshot_figures(&output_dir);
+    let mut figures: Vec<String> = watch::changed_files(&before, &after)
+        .into_iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect();
+    figures.sort();
+
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
 
-    Ok(format!("{}{}", stdout, stderr))
+    Ok(FigureRunResult {
+        output: format!("{}{}", stdout, stderr),
+        success: output.status.success(),
+        figures,
+    })
 }
 
 #[tauri::command]
@@ -765,8 +3090,315 @@ pub async fn run_script_with_uv_streaming(
     window: Window,
     project_path: String,
     script_path: String,
+    raw_output: Option<bool>,
+    parse_log_levels: Option<bool>,
+    process_manager: State<'_, ProcessManager>,
+) -> Result<String, String> {
+    // Use 'uv run' to execute script with streaming output
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "python", &script_path]);
+
+    let options = StreamOptions {
+        raw_output: raw_output.unwrap_or(false),
+        parse_log_levels: parse_log_levels.unwrap_or(false),
+        ..StreamOptions::default()
+    };
+    spawn_streaming_process(window, cmd, &process_manager, options).await?;
+
+    // Return immediately so UI stays responsive
+    Ok("UV run started successfully".to_string())
+}
+
+#[tauri::command]
+pub async fn run_project_script(
+    window: Window,
+    project_path: String,
+    script_name: String,
+    raw_output: Option<bool>,
+    parse_log_levels: Option<bool>,
+    process_manager: State<'_, ProcessManager>,
+) -> Result<String, String> {
+    // Make sure the entry point is actually declared before launching it
+    let pyproject_path = Path::new(&project_path).join("pyproject.toml");
+    let content = std::fs::read_to_string(&pyproject_path)
+        .map_err(|e| format!("Failed to read pyproject.toml: {}", e))?;
+    let value: toml::Value = content.parse()
+        .map_err(|e| format!("Failed to parse pyproject.toml: {}", e))?;
+
+    let available: Vec<String> = value
+        .get("project")
+        .and_then(|p| p.get("scripts"))
+        .and_then(|s| s.as_table())
+        .map(|scripts| scripts.keys().cloned().collect())
+        .unwrap_or_default();
+
+    if !available.contains(&script_name) {
+        return Err(if available.is_empty() {
+            format!("Script '{}' not found: pyproject.toml defines no [project.scripts]", script_name)
+        } else {
+            format!(
+                "Script '{}' not found in [project.scripts]. Available scripts: {}",
+                script_name,
+                available.join(", ")
+            )
+        });
+    }
+
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", &script_name]).current_dir(&project_path);
+    let options = StreamOptions {
+        raw_output: raw_output.unwrap_or(false),
+        parse_log_levels: parse_log_levels.unwrap_or(false),
+        ..StreamOptions::default()
+    };
+    spawn_streaming_process(window, cmd, &process_manager, options).await?;
+
+    Ok(format!("Script '{}' started successfully", script_name))
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CommandStarted {
+    pub id: String,
+    pub program: String,
+    pub pid: u32,
+}
+
+fn venv_bin_dir(project_path: &str) -> PathBuf {
+    let bin = if cfg!(target_os = "windows") {
+        "Scripts"
+    } else {
+        "bin"
+    };
+    Path::new(&venv_dir(project_path)).join(bin)
+}
+
+// Find `program` in the first of `dirs` that has it. On Windows a bare name
+// also matches the usual executable extensions.
+fn find_program(dirs: &[PathBuf], program: &str) -> Option<PathBuf> {
+    let mut names = vec![program.to_string()];
+    if cfg!(target_os = "windows") && Path::new(program).extension().is_none() {
+        names.extend(
+            ["exe", "cmd", "bat"]
+                .iter()
+                .map(|ext| format!("{}.{}", program, ext)),
+        );
+    }
+    dirs.iter()
+        .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+        .find(|candidate| candidate.is_file())
+}
+
+// Run an arbitrary program (pytest, uvicorn, flask, ...) as the tracked script
+// process with the project venv activated: its bin directory is searched
+// before PATH, for the program and for anything the program starts. Output is
+// streamed like `run_script_with_output_streaming`, preceded by a
+// command-started event carrying `id`.
+#[tauri::command]
+pub async fn run_command_streaming(
+    window: Window,
+    project_path: String,
+    program: String,
+    args: Vec<String>,
+    id: String,
+    process_manager: State<'_, ProcessManager>,
+) -> Result<String, String> {
+    let program = program.trim().to_string();
+    if program.is_empty() {
+        return Err("No program given".to_string());
+    }
+
+    let bin_dir = venv_bin_dir(&project_path);
+    let mut search_dirs = vec![bin_dir.clone()];
+    if let Some(path) = std::env::var_os("PATH") {
+        search_dirs.extend(std::env::split_paths(&path));
+    }
+
+    // A program given as a path is taken relative to the project
+    let resolved = if program.contains('/') || program.contains('\\') {
+        Some(Path::new(&project_path).join(&program)).filter(|path| path.is_file())
+    } else {
+        find_program(&search_dirs, &program)
+    };
+    let resolved = match resolved {
+        Some(resolved) => resolved,
+        None => {
+            return Err(format!(
+                "Program '{}' not found in the project environment or on PATH",
+                program
+            ))
+        }
+    };
+    let path = std::env::join_paths(&search_dirs).map_err(|e| format!("Invalid PATH: {}", e))?;
+
+    let mut cmd = Command::new(&resolved);
+    cmd.args(&args)
+        .current_dir(&project_path)
+        .env("PATH", path)
+        .env("VIRTUAL_ENV", venv_dir(&project_path));
+    let options = StreamOptions {
+        command_started: Some((id, program.clone())),
+        ..StreamOptions::default()
+    };
+    spawn_streaming_process(window, cmd, &process_manager, options).await?;
+
+    Ok(format!("'{}' started successfully", program))
+}
+
+#[tauri::command]
+pub async fn run_script_watch(
+    window: Window,
+    project_path: String,
+    script_path: String,
+    watch_paths: Vec<String>,
+    watch_id: String,
     process_manager: State<'_, ProcessManager>,
+    watch_manager: State<'_, WatchManager>,
 ) -> Result<String, String> {
+    let roots: Vec<PathBuf> = if watch_paths.is_empty() {
+        vec![PathBuf::from(&project_path)]
+    } else {
+        watch_paths
+            .iter()
+            .map(|p| Path::new(&project_path).join(p))
+            .collect()
+    };
+
+    let uv_run = |project_path: &str, script_path: &str| {
+        let mut cmd = uv_project_command(project_path);
+        cmd.args(["run", "python", script_path]).current_dir(project_path);
+        cmd
+    };
+
+    spawn_streaming_process(
+        window.clone(),
+        uv_run(&project_path, &script_path),
+        &process_manager,
+        StreamOptions::default(),
+    )
+    .await?;
+
+    let running = watch::register_watch(&watch_manager, &watch_id).await;
+    let process_manager = Arc::clone(&*process_manager);
+
+    tokio::spawn(async move {
+        let snapshot_all = |roots: &[PathBuf]| {
+            roots
+                .iter()
+                .flat_map(|root| watch::snapshot_files(root, "py"))
+                .collect::<watch::Snapshot>()
+        };
+        let mut snapshot = snapshot_all(&roots);
+        let mut pending: Vec<PathBuf> = Vec::new();
+
+        while running.load(Ordering::SeqCst) {
+            tokio::time::sleep(tokio::time::Duration::from_millis(watch::POLL_INTERVAL_MS)).await;
+
+            let current = snapshot_all(&roots);
+            let changed = watch::changed_files(&snapshot, &current);
+            snapshot = current;
+
+            // Wait for a quiet poll interval so a burst of saves restarts once
+            if !changed.is_empty() {
+                pending.extend(changed);
+                continue;
+            }
+            if pending.is_empty() || !running.load(Ordering::SeqCst) {
+                continue;
+            }
+
+            let changed_files: Vec<String> = pending
+                .drain(..)
+                .map(|p| p.to_string_lossy().to_string())
+                .collect();
+            let _ = window.emit("script-restarting", &changed_files);
+
+            if let Err(e) = spawn_streaming_process(
+                window.clone(),
+                uv_run(&project_path, &script_path),
+                &process_manager,
+                StreamOptions::default(),
+            )
+            .await
+            {
+                emit_script_line(&window, "stderr", &e);
+            }
+        }
+    });
+
+    Ok("Watching for changes".to_string())
+}
+
+#[tauri::command]
+pub async fn stop_script_watch(
+    watch_id: String,
+    process_manager: State<'_, ProcessManager>,
+    watch_manager: State<'_, WatchManager>,
+) -> Result<bool, String> {
+    let stopped = watch::stop_watch(&watch_manager, &watch_id).await;
+    if stopped {
+        let mut current_process = process_manager.lock().await;
+        if let Some(mut child) = current_process.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+    Ok(stopped)
+}
+
+// How `spawn_streaming_process` forwards the script's output
+#[derive(Clone, Default)]
+struct StreamOptions {
+    // Every output line is also appended here, see `append_log_line`
+    log: Option<Arc<StdMutex<File>>>,
+    timestamp: bool,
+    // Emit chunks as they arrive instead of whole lines, see `forward_raw_output`
+    raw_output: bool,
+    // Also emit script-log events for lines written by `logging`
+    parse_log_levels: bool,
+    // `(id, program)` for a command-started event, sent before any output
+    command_started: Option<(String, String)>,
+}
+
+// Every run command frames output lines here, so the terminal renders them the
+// same whichever way the script was started: the line without its terminator
+// followed by a single "\n"
+fn emit_script_line(window: &Window, stream: &str, line: &str) {
+    let event = if stream == "stdout" {
+        "script-output"
+    } else {
+        "script-error"
+    };
+    let _ = window.emit(event, &format!("{}\n", line));
+}
+
+// Forward one of the script's output streams to the window until it closes
+fn forward_output(window: &Window, reader: impl Read, stream: &str, options: &StreamOptions) {
+    if options.raw_output {
+        forward_raw_output(window, reader, stream, options);
+        return;
+    }
+
+    for line in BufReader::new(reader).lines().map_while(Result::ok) {
+        emit_script_line(window, stream, &line);
+        if options.parse_log_levels {
+            emit_log_entry(window, &line, stream);
+        }
+        if let Some(ref log) = options.log {
+            append_log_line(log, &line, options.timestamp);
+        }
+    }
+}
+
+// Spawn `cmd` as the tracked script process, replacing any running one, and
+// stream its output to the window as script-output/script-error events (or
+// script-raw-output chunks), emitting script-completed when done. Returns
+// the pid of the started process.
+async fn spawn_streaming_process(
+    window: Window,
+    mut cmd: Command,
+    process_manager: &ProcessManager,
+    mut options: StreamOptions,
+) -> Result<u32, String> {
     // Kill any existing process first
     {
         let mut current_process = process_manager.lock().await;
@@ -776,102 +3408,160 @@ pub async fn run_script_with_uv_streaming(
         }
     }
 
-    // Use 'uv run' to execute script with streaming output
-    let mut cmd = Command::new("uv");
-    cmd.args(&["run", "python", &script_path])
-        .current_dir(&project_path)
-        .stdout(Stdio::piped())
+    cmd.stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .stdin(Stdio::null());
+    if options.raw_output {
+        cmd.env("PYTHONUNBUFFERED", "1");
+    }
+    new_process_group(&mut cmd);
     #[cfg(target_os = "windows")]
     {
         cmd.creation_flags(0x08000000);
     }
     let mut child = cmd.spawn()
-        .map_err(|e| format!("Failed to start uv run: {}", e))?;
+        .map_err(|e| format!("Failed to start process: {}", e))?;
 
+    let pid = child.id();
     let stdout = child.stdout.take().unwrap();
     let stderr = child.stderr.take().unwrap();
 
     // Store the process in the manager
     {
         let mut current_process = process_manager.lock().await;
-        *current_process = Some(child);
+        *current_process = Some(ProcessTree::new(child));
+    }
+
+    if let Some((id, program)) = options.command_started.take() {
+        let _ = window.emit("command-started", CommandStarted { id, program, pid });
     }
 
-    // Handle stdout in a separate task
     let window_stdout = window.clone();
+    let options_stdout = options.clone();
     let stdout_handle = tokio::spawn(async move {
-        let reader = BufReader::new(stdout);
-        for line in reader.lines() {
-            if let Ok(line) = line {
-                let _ = window_stdout.emit("script-output", &format!("{}\\n", line));
-            }
-        }
+        forward_output(&window_stdout, stdout, "stdout", &options_stdout);
     });
 
-    // Handle stderr in a separate task
     let window_stderr = window.clone();
     let stderr_handle = tokio::spawn(async move {
-        let reader = BufReader::new(stderr);
-        for line in reader.lines() {
-            if let Ok(line) = line {
-                let _ = window_stderr.emit("script-error", &format!("{}\\n", line));
-            }
-        }
+        forward_output(&window_stderr, stderr, "stderr", &options);
     });
 
-    // Handle process completion in background task
-    let process_manager_wait = Arc::clone(&*process_manager);
-    let completion_window = window.clone();
+    // Poll for completion in the background so the command returns immediately
+    let process_manager_wait = Arc::clone(process_manager);
     tokio::spawn(async move {
         loop {
-            // Check if process still exists and wait for a short time
-            let should_continue = {
+            {
                 let mut current_process = process_manager_wait.lock().await;
-                if let Some(ref mut child) = *current_process {
-                    // Try to wait without blocking indefinitely
-                    match child.try_wait() {
-                        Ok(Some(status)) => {
-                            // Process has completed
-                            *current_process = None;
-                            let _ = completion_window.emit("script-completed", status.success());
-                            break;
-                        }
-                        Ok(None) => {
-                            // Process is still running, continue loop
-                            true
-                        }
-                        Err(_) => {
-                            // Error occurred, consider process stopped
-                            *current_process = None;
-                            let _ = completion_window.emit("script-completed", false);
-                            break;
-                        }
+                let tracked = current_process.as_mut().filter(|child| child.id() == pid);
+                match tracked.map(|child| child.try_wait()) {
+                    Some(Ok(Some(status))) => {
+                        *current_process = None;
+                        let _ = window.emit("script-completed", status.success());
+                        break;
+                    }
+                    Some(Ok(None)) => {}
+                    Some(Err(_)) => {
+                        *current_process = None;
+                        let _ = window.emit("script-completed", false);
+                        break;
+                    }
+                    None => {
+                        // Process was stopped externally or replaced by a new run
+                        let _ = window.emit("script-completed", false);
+                        break;
                     }
-                } else {
-                    // Process was stopped externally
-                    let _ = completion_window.emit("script-completed", false);
-                    break;
                 }
-            };
-
-            if !should_continue {
-                break;
             }
 
-            // Wait a short time before checking again
             tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
         }
 
-        // Wait for output handlers to complete
         let _ = stdout_handle.await;
         let _ = stderr_handle.await;
     });
 
-    // Return immediately so UI stays responsive
-    Ok("UV run started successfully".to_string())
+    Ok(pid)
 }
 
+// Quiet period after the last edit to pyproject.toml before syncing
+const AUTOSYNC_DEBOUNCE_MS: u64 = 500;
+
+// Run `uv sync` whenever pyproject.toml is edited, emitting
+// `autosync-started` and `autosync-completed`
+#[tauri::command]
+pub async fn start_autosync(
+    window: Window,
+    project_path: String,
+    watch_id: String,
+    watch_manager: State<'_, WatchManager>,
+) -> Result<(), String> {
+    let pyproject_path = Path::new(&project_path).join("pyproject.toml");
+    if !pyproject_path.is_file() {
+        return Err("pyproject.toml not found".to_string());
+    }
+
+    let running = watch::register_watch(&watch_manager, &watch_id).await;
+
+    tokio::spawn(async move {
+        let mut snapshot = watch::snapshot_files(&pyproject_path, "toml");
+        // Only a change in content triggers a sync, so rewrites by uv itself
+        // or saves without edits are ignored
+        let mut synced_content = std::fs::read_to_string(&pyproject_path).ok();
+        let mut last_change: Option<std::time::Instant> = None;
+
+        while running.load(Ordering::SeqCst) {
+            tokio::time::sleep(tokio::time::Duration::from_millis(watch::POLL_INTERVAL_MS)).await;
+
+            let current = watch::snapshot_files(&pyproject_path, "toml");
+            if !watch::changed_files(&snapshot, &current).is_empty() {
+                last_change = Some(std::time::Instant::now());
+            }
+            snapshot = current;
+
+            let settled = last_change.map_or(false, |changed| {
+                changed.elapsed() >= std::time::Duration::from_millis(AUTOSYNC_DEBOUNCE_MS)
+            });
+            if !settled || !running.load(Ordering::SeqCst) {
+                continue;
+            }
+            last_change = None;
+
+            let content = std::fs::read_to_string(&pyproject_path).ok();
+            if content.is_none() || content == synced_content {
+                continue;
+            }
 
+            let _ = window.emit("autosync-started", &watch_id);
+            let event = match sync_uv_project(project_path.clone()).await {
+                Ok(result) => AutosyncEvent {
+                    watch_id: watch_id.clone(),
+                    success: true,
+                    output: result.output,
+                    lock_diff: Some(result.lock_diff),
+                },
+                Err(e) => AutosyncEvent {
+                    watch_id: watch_id.clone(),
+                    success: false,
+                    output: e,
+                    lock_diff: None,
+                },
+            };
+            let _ = window.emit("autosync-completed", event);
+
+            // Take in whatever uv wrote during the sync as the new baseline
+            snapshot = watch::snapshot_files(&pyproject_path, "toml");
+            synced_content = std::fs::read_to_string(&pyproject_path).ok();
+        }
+    });
 
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn stop_autosync(
+    watch_id: String,
+    watch_manager: State<'_, WatchManager>,
+) -> Result<bool, String> {
+    Ok(watch::stop_watch(&watch_manager, &watch_id).await)
+}
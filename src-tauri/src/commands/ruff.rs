@@ -1,9 +1,18 @@
 use serde::{Deserialize, Serialize};
-use std::process::{Command, Stdio};
-#[cfg(target_os = "windows")]
-use std::os::windows::process::CommandExt;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tauri::{Emitter, State, Window};
+use tokio::sync::Semaphore;
 
-#[derive(Serialize, Deserialize)]
+use super::git::run_git;
+use super::project::uv_project_command;
+use super::settings::uv_command;
+use super::symbols::import_statements;
+use super::watch::{self, WatchManager};
+
+#[derive(Serialize, Deserialize, Clone)]
 pub struct RuffDiagnostic {
     pub rule: String,
     pub message: String,
@@ -15,25 +24,27 @@ pub struct RuffDiagnostic {
     pub filename: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct RuffCheckResult {
     pub diagnostics: Vec<RuffDiagnostic>,
     pub fixed: u32,
     pub errors: Vec<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RuffWatchEvent {
+    pub watch_id: String,
+    pub file_path: String,
+    pub result: RuffCheckResult,
+}
+
 #[tauri::command]
 pub async fn check_ruff_installed() -> Result<bool, String> {
-    let mut cmd = Command::new("uv");
+    let mut cmd = uv_command();
     cmd.args(&["run", "ruff", "--version"]) 
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        // CREATE_NO_WINDOW to avoid flashing console windows in release
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output();
 
     match output {
@@ -44,16 +55,12 @@ pub async fn check_ruff_installed() -> Result<bool, String> {
 
 #[tauri::command]
 pub async fn install_ruff_with_uv(project_path: String) -> Result<String, String> {
-    let mut cmd = Command::new("uv");
+    let mut cmd = uv_project_command(&project_path);
     cmd.args(&["add", "--dev", "ruff"]) 
         .current_dir(&project_path)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd
         .output()
         .map_err(|e| format!("Failed to execute uv: {}", e))?;
@@ -74,7 +81,7 @@ pub async fn ruff_check_file(
     println!("🔍 [RUFF] project_path: {}", project_path);
     println!("🔍 [RUFF] file_path: {}", file_path);
 
-    let mut cmd = Command::new("uv");
+    let mut cmd = uv_project_command(&project_path);
     cmd.args(&[
             "run",
             "ruff",
@@ -87,10 +94,6 @@ pub async fn ruff_check_file(
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| {
             let error_msg = format!("Failed to execute uv run ruff check: {}", e);
@@ -198,7 +201,7 @@ pub async fn ruff_check_file(
 
 #[tauri::command]
 pub async fn ruff_check_project(project_path: String) -> Result<RuffCheckResult, String> {
-    let mut cmd = Command::new("uv");
+    let mut cmd = uv_project_command(&project_path);
     cmd.args(&[
             "run",
             "ruff",
@@ -211,10 +214,6 @@ pub async fn ruff_check_project(project_path: String) -> Result<RuffCheckResult,
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv run ruff check: {}", e))?;
 
@@ -297,18 +296,301 @@ pub async fn ruff_check_project(project_path: String) -> Result<RuffCheckResult,
     }
 }
 
+// Diagnostics from ruff's JSON output, in the shape ruff_check_project reports.
+// Syntax errors, including syntax the target version lacks, have no rule code
+// and are dropped unless `syntax_errors` is set.
+fn ruff_diagnostics(values: &[serde_json::Value], syntax_errors: bool) -> Vec<RuffDiagnostic> {
+    values
+        .iter()
+        .filter_map(|diag| {
+            let (line, column) = json_position(diag.get("location"))?;
+            let (end_line, end_column) =
+                json_position(diag.get("end_location")).unwrap_or((line, column + 1));
+            let code = diag.get("code").and_then(|code| code.as_str());
+            if code.is_none() && !syntax_errors {
+                return None;
+            }
+            Some(RuffDiagnostic {
+                rule: code.unwrap_or("syntax-error").to_string(),
+                message: diag.get("message")?.as_str()?.to_string(),
+                line: line as u32,
+                column: column as u32,
+                end_line: end_line as u32,
+                end_column: end_column as u32,
+                severity: if code.is_some() { "warning" } else { "error" }.to_string(),
+                filename: diag.get("filename")?.as_str()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+// Run `ruff check` with extra options on the given paths, relative to the
+// project
+fn ruff_check_paths(
+    project_path: &str,
+    options: &[&str],
+    paths: &[String],
+    syntax_errors: bool,
+) -> Result<RuffCheckResult, String> {
+    let mut cmd = uv_project_command(project_path);
+    let output = cmd
+        .args(["run", "ruff", "check", "--output-format=json", "--no-cache"])
+        .args(options)
+        .arg("--")
+        .args(paths)
+        .current_dir(project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to execute uv run ruff check: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let values: Vec<serde_json::Value> = if stdout.trim().is_empty() {
+        if !output.status.success() {
+            return Err(stderr.to_string());
+        }
+        Vec::new()
+    } else {
+        serde_json::from_str(&stdout).map_err(|e| format!("Failed to parse ruff output: {}", e))?
+    };
+
+    Ok(RuffCheckResult {
+        diagnostics: ruff_diagnostics(&values, syntax_errors),
+        fixed: 0,
+        errors: if stderr.trim().is_empty() {
+            vec![]
+        } else {
+            vec![stderr.to_string()]
+        },
+    })
+}
+
+// Lint only the Python files changed since the last commit, plus untracked
+// ones. A repository without commits is checked as a whole.
+#[tauri::command]
+pub async fn ruff_check_changed(project_path: String) -> Result<RuffCheckResult, String> {
+    match run_git(&project_path, &["rev-parse", "--verify", "--quiet", "HEAD"]) {
+        Ok(_) => {}
+        Err(e) if e.is_empty() => return ruff_check_project(project_path).await,
+        Err(e) => return Err(e),
+    }
+
+    // Paths come back relative to the project directory; deleted files are
+    // filtered out since there is nothing left to lint
+    let changed = run_git(
+        &project_path,
+        &[
+            "diff",
+            "--name-only",
+            "-z",
+            "--relative",
+            "--diff-filter=d",
+            "HEAD",
+        ],
+    )?;
+    let untracked = run_git(
+        &project_path,
+        &["ls-files", "-z", "--others", "--exclude-standard"],
+    )?;
+
+    let mut files: Vec<String> = Vec::new();
+    for file in changed.split('\0').chain(untracked.split('\0')) {
+        if file.ends_with(".py")
+            && Path::new(&project_path).join(file).is_file()
+            && !files.iter().any(|f| f == file)
+        {
+            files.push(file.to_string());
+        }
+    }
+
+    if files.is_empty() {
+        return Ok(RuffCheckResult {
+            diagnostics: vec![],
+            fixed: 0,
+            errors: vec![],
+        });
+    }
+    ruff_check_paths(&project_path, &[], &files, false)
+}
+
+// Files per ruff invocation when streaming project diagnostics, and how many
+// invocations run at once
+const DIAGNOSTICS_BATCH_SIZE: usize = 25;
+const DIAGNOSTICS_CONCURRENCY: usize = 4;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DiagnosticsBatch {
+    // Every file checked in the batch, so files without issues can be cleared
+    pub files: Vec<String>,
+    pub diagnostics: Vec<RuffDiagnostic>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DiagnosticsComplete {
+    pub files_checked: usize,
+    pub diagnostic_count: usize,
+    pub errors: Vec<String>,
+}
+
+// The files ruff would check in the project, honouring its excludes
+fn ruff_project_files(project_path: &str) -> Result<Vec<String>, String> {
+    let mut cmd = uv_project_command(project_path);
+    let output = cmd
+        .args(["run", "ruff", "check", ".", "--show-files", "--no-cache"])
+        .current_dir(project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to execute uv run ruff check: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+// Like ruff_check_project, but checks the project in batches and emits a
+// `diagnostics-batch` event as each one finishes, then `diagnostics-complete`.
+// Returns the combined result.
+#[tauri::command]
+pub async fn ruff_check_project_streaming(
+    window: Window,
+    project_path: String,
+) -> Result<RuffCheckResult, String> {
+    let files = ruff_project_files(&project_path)?;
+    let semaphore = Arc::new(Semaphore::new(DIAGNOSTICS_CONCURRENCY));
+
+    let handles: Vec<_> = files
+        .chunks(DIAGNOSTICS_BATCH_SIZE)
+        .map(|chunk| {
+            let files = chunk.to_vec();
+            let project_path = project_path.clone();
+            let window = window.clone();
+            let semaphore = semaphore.clone();
+            tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let batch = files.clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    ruff_check_paths(&project_path, &[], &batch, false)
+                })
+                .await
+                .map_err(|e| format!("Ruff check task failed: {}", e))
+                .and_then(|result| result);
+                if let Ok(result) = &result {
+                    let _ = window.emit(
+                        "diagnostics-batch",
+                        DiagnosticsBatch {
+                            files,
+                            diagnostics: result.diagnostics.clone(),
+                        },
+                    );
+                }
+                result
+            })
+        })
+        .collect();
+
+    let mut combined = RuffCheckResult {
+        diagnostics: vec![],
+        fixed: 0,
+        errors: vec![],
+    };
+    for handle in handles {
+        match handle.await {
+            Ok(Ok(result)) => {
+                combined.diagnostics.extend(result.diagnostics);
+                combined.errors.extend(result.errors);
+            }
+            Ok(Err(e)) => combined.errors.push(e),
+            Err(e) => combined
+                .errors
+                .push(format!("Ruff check task failed: {}", e)),
+        }
+    }
+
+    let _ = window.emit(
+        "diagnostics-complete",
+        DiagnosticsComplete {
+            files_checked: files.len(),
+            diagnostic_count: combined.diagnostics.len(),
+            errors: combined.errors.clone(),
+        },
+    );
+    Ok(combined)
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CompatibilityResult {
+    // Ruff's form of the target, e.g. "py39"
+    pub target_version: String,
+    pub diagnostics: Vec<RuffDiagnostic>,
+    pub errors: Vec<String>,
+}
+
+// Accept "3.9", "39" or "py39"
+fn ruff_target_version(version: &str) -> Option<String> {
+    let version = version.trim();
+    let digits: String = version
+        .strip_prefix("py")
+        .unwrap_or(version)
+        .chars()
+        .filter(|c| *c != '.')
+        .collect();
+    if digits.len() < 2 || !digits.chars().all(|c| c.is_ascii_digit()) || !digits.starts_with('3') {
+        return None;
+    }
+    Some(format!("py{}", digits))
+}
+
+// Check the project against a target Python version: pyupgrade (UP) rules
+// point out code that can be modernized for it, and ruff reports syntax the
+// version doesn't support as syntax errors
+#[tauri::command]
+pub async fn check_python_compatibility(
+    project_path: String,
+    target_version: String,
+) -> Result<CompatibilityResult, String> {
+    let target = ruff_target_version(&target_version)
+        .ok_or_else(|| format!("Invalid Python version: {}", target_version))?;
+
+    let result = ruff_check_paths(
+        &project_path,
+        &["--select=UP", "--target-version", &target],
+        &[".".to_string()],
+        true,
+    )?;
+    let diagnostics = result
+        .diagnostics
+        .into_iter()
+        .map(|mut diag| {
+            diag.message = format!("{} (target {})", diag.message, target);
+            diag
+        })
+        .collect();
+
+    Ok(CompatibilityResult {
+        target_version: target,
+        diagnostics,
+        errors: result.errors,
+    })
+}
+
 #[tauri::command]
 pub async fn ruff_format_file(project_path: String, file_path: String) -> Result<String, String> {
-    let mut cmd = Command::new("uv");
+    let mut cmd = uv_project_command(&project_path);
     cmd.args(&["run", "ruff", "format", &file_path, "--no-cache"])
         .current_dir(&project_path)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv run ruff format: {}", e))?;
 
@@ -322,16 +604,12 @@ pub async fn ruff_format_file(project_path: String, file_path: String) -> Result
 
 #[tauri::command]
 pub async fn ruff_format_project(project_path: String) -> Result<String, String> {
-    let mut cmd = Command::new("uv");
+    let mut cmd = uv_project_command(&project_path);
     cmd.args(&["run", "ruff", "format", ".", "--no-cache"])
         .current_dir(&project_path)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv run ruff format: {}", e))?;
 
@@ -345,12 +623,38 @@ pub async fn ruff_format_project(project_path: String) -> Result<String, String>
     }
 }
 
+#[tauri::command]
+pub async fn ruff_format_project_check(project_path: String) -> Result<Vec<String>, String> {
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "ruff", "format", "--check", ".", "--no-cache"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv run ruff format: {}", e))?;
+
+    // Exit code 1 means some files would be reformatted
+    if !output.status.success() && output.status.code() != Some(1) {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let files = stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("Would reformat: "))
+        .map(|path| path.trim().to_string())
+        .collect();
+
+    Ok(files)
+}
+
 #[tauri::command]
 pub async fn ruff_fix_file(
     project_path: String,
     file_path: String,
 ) -> Result<RuffCheckResult, String> {
-    let mut cmd = Command::new("uv");
+    let mut cmd = uv_project_command(&project_path);
     cmd.args(&[
             "run",
             "ruff",
@@ -364,10 +668,6 @@ pub async fn ruff_fix_file(
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    #[cfg(target_os = "windows")]
-    {
-        cmd.creation_flags(0x08000000);
-    }
     let output = cmd.output()
         .map_err(|e| format!("Failed to execute uv run ruff fix: {}", e))?;
 
@@ -456,6 +756,551 @@ pub async fn ruff_fix_file(
     }
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RuffFix {
+    pub rule: String,
+    pub message: String,
+    pub line: u32,
+    pub column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    // What the fix does, e.g. "Remove unused import: `os`"
+    pub fix_message: Option<String>,
+    // "safe", "unsafe" or "display-only"
+    pub applicability: String,
+}
+
+// One replacement from a ruff fix. Rows and columns are 1-based, with
+// columns counted in characters.
+struct FixEdit {
+    content: String,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+fn json_position(value: Option<&serde_json::Value>) -> Option<(usize, usize)> {
+    let value = value?;
+    Some((
+        value.get("row")?.as_u64()? as usize,
+        value.get("column")?.as_u64()? as usize,
+    ))
+}
+
+// Run `ruff check` without fixing and return the raw JSON diagnostics
+fn ruff_check_json(
+    project_path: &str,
+    file_path: &str,
+    select: Option<&str>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let mut cmd = uv_project_command(project_path);
+    cmd.args(["run", "ruff", "check", file_path, "--output-format=json", "--no-cache"]);
+    if let Some(rule) = select {
+        cmd.arg(format!("--select={}", rule));
+    }
+    let output = cmd
+        .current_dir(project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to execute uv run ruff check: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim().is_empty() {
+        return if output.status.success() {
+            Ok(Vec::new())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        };
+    }
+    serde_json::from_str(&stdout).map_err(|e| format!("Failed to parse ruff output: {}", e))
+}
+
+// List the diagnostics in a file that ruff knows how to fix
+#[tauri::command]
+pub async fn get_available_fixes(
+    project_path: String,
+    file_path: String,
+) -> Result<Vec<RuffFix>, String> {
+    let diagnostics = ruff_check_json(&project_path, &file_path, None)?;
+
+    let fixes = diagnostics
+        .iter()
+        .filter_map(|diag| {
+            let fix = diag.get("fix").filter(|fix| !fix.is_null())?;
+            let (line, column) = json_position(diag.get("location"))?;
+            let (end_line, end_column) =
+                json_position(diag.get("end_location")).unwrap_or((line, column + 1));
+            Some(RuffFix {
+                rule: diag.get("code")?.as_str()?.to_string(),
+                message: diag.get("message")?.as_str()?.to_string(),
+                line: line as u32,
+                column: column as u32,
+                end_line: end_line as u32,
+                end_column: end_column as u32,
+                fix_message: fix
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .map(String::from),
+                applicability: fix
+                    .get("applicability")
+                    .and_then(|a| a.as_str())
+                    .unwrap_or("safe")
+                    .to_string(),
+            })
+        })
+        .collect();
+
+    Ok(fixes)
+}
+
+// Byte offset of a 1-based row/column, clamped to the end of the text
+fn byte_offset(content: &str, (row, column): (usize, usize)) -> usize {
+    let mut line_start = 0;
+    for _ in 1..row {
+        match content[line_start..].find('\n') {
+            Some(newline) => line_start += newline + 1,
+            None => return content.len(),
+        }
+    }
+    content[line_start..]
+        .char_indices()
+        .nth(column.saturating_sub(1))
+        .map_or(content.len(), |(offset, _)| line_start + offset)
+}
+
+fn fix_edits(diag: &serde_json::Value) -> Option<Vec<FixEdit>> {
+    let fix = diag.get("fix").filter(|fix| !fix.is_null())?;
+    if fix.get("applicability").and_then(|a| a.as_str()) == Some("display-only") {
+        return None;
+    }
+    fix.get("edits")?
+        .as_array()?
+        .iter()
+        .map(|edit| {
+            Some(FixEdit {
+                content: edit
+                    .get("content")
+                    .and_then(|c| c.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+                start: json_position(edit.get("location"))?,
+                end: json_position(edit.get("end_location"))?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FixAndFormatResult {
+    pub fixed: u32,
+    pub formatted: bool,
+    pub remaining_diagnostics: Vec<RuffDiagnostic>,
+    // The file as it is on disk afterwards, for the editor buffer
+    pub content: String,
+    pub errors: Vec<String>,
+}
+
+// The fix count from ruff's summary, e.g. "Found 3 errors (2 fixed, 1 remaining)."
+fn parse_fixed_count(output: &str) -> u32 {
+    output
+        .lines()
+        .filter(|line| line.starts_with("Found "))
+        .find_map(|line| {
+            let counts = &line[line.find('(')? + 1..];
+            counts[..counts.find(" fixed")?].parse().ok()
+        })
+        .unwrap_or(0)
+}
+
+// Apply ruff's fixes and then format the file, in that order since fixes can
+// leave code the formatter would change
+#[tauri::command]
+pub async fn fix_and_format_file(
+    project_path: String,
+    file_path: String,
+) -> Result<FixAndFormatResult, String> {
+    let full_path = Path::new(&project_path).join(&file_path);
+    let mut errors = Vec::new();
+
+    // Text output, since only its summary line reports how many fixes were applied
+    let mut cmd = uv_project_command(&project_path);
+    let output = cmd
+        .args(["run", "ruff", "check", &file_path, "--fix", "--no-cache"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to execute uv run ruff fix: {}", e))?;
+    if output.stdout.is_empty() && !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    let fixed = parse_fixed_count(&String::from_utf8_lossy(&output.stdout));
+
+    let unformatted =
+        std::fs::read_to_string(&full_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    // A file with syntax errors can't be formatted; the fixes still stand
+    if let Err(e) = ruff_format_file(project_path.clone(), file_path.clone()).await {
+        errors.push(e);
+    }
+    let content =
+        std::fs::read_to_string(&full_path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let remaining = ruff_check_json(&project_path, &file_path, None)?;
+    Ok(FixAndFormatResult {
+        fixed,
+        formatted: content != unformatted,
+        remaining_diagnostics: ruff_diagnostics(&remaining, false),
+        content,
+        errors,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ImportCleanupResult {
+    // Imported names (or modules) that were dropped as unused
+    pub removed: u32,
+    // Remaining imports that ended up in a different position
+    pub reordered: u32,
+    pub content: String,
+}
+
+// Each imported module or `from` name, in source order
+fn imported_names(source: &str) -> Vec<String> {
+    import_statements(source)
+        .into_iter()
+        .flat_map(|statement| {
+            if statement.names.is_empty() {
+                vec![statement.module]
+            } else {
+                statement
+                    .names
+                    .iter()
+                    .map(|name| format!("{}.{}", statement.module, name))
+                    .collect()
+            }
+        })
+        .collect()
+}
+
+// Sort imports and drop unused ones (ruff's I and F401 rules) in one pass
+#[tauri::command]
+pub async fn cleanup_imports(
+    project_path: String,
+    file_path: String,
+) -> Result<ImportCleanupResult, String> {
+    let full_path = Path::new(&project_path).join(&file_path);
+    let before =
+        std::fs::read_to_string(&full_path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let mut cmd = uv_project_command(&project_path);
+    let output = cmd
+        .args([
+            "run",
+            "ruff",
+            "check",
+            &file_path,
+            "--select=I,F401",
+            "--fix",
+            "--no-cache",
+        ])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to execute uv run ruff fix: {}", e))?;
+    if output.stdout.is_empty() && !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let content =
+        std::fs::read_to_string(&full_path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+    // Compare the imports that survived in their old order against the new
+    // order; anything removed from the after list was dropped as unused
+    let mut remaining = imported_names(&content);
+    let mut kept_in_old_order = Vec::new();
+    let mut removed = 0;
+    for name in imported_names(&before) {
+        match remaining.iter().position(|other| other == &name) {
+            Some(index) => {
+                remaining.remove(index);
+                kept_in_old_order.push(name);
+            }
+            None => removed += 1,
+        }
+    }
+    let reordered = kept_in_old_order
+        .iter()
+        .zip(imported_names(&content).iter())
+        .filter(|(old, new)| old != new)
+        .count();
+
+    Ok(ImportCleanupResult {
+        removed,
+        reordered: reordered as u32,
+        content,
+    })
+}
+
+// Apply the fixes for one rule. With a line, only the diagnostics starting on
+// that line are fixed; otherwise every occurrence of the rule in the file is.
+// Returns the file's diagnostics after the fix.
+#[tauri::command]
+pub async fn apply_fix(
+    project_path: String,
+    file_path: String,
+    rule: String,
+    line: Option<u32>,
+) -> Result<RuffCheckResult, String> {
+    let rule = rule.trim();
+    if rule.is_empty() || !rule.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("Invalid rule code: {}", rule));
+    }
+
+    let fixed = match line {
+        Some(line) => {
+            let diagnostics = ruff_check_json(&project_path, &file_path, Some(rule))?;
+            let mut edits: Vec<FixEdit> = diagnostics
+                .iter()
+                .filter(|diag| {
+                    json_position(diag.get("location")).map(|(row, _)| row) == Some(line as usize)
+                })
+                .filter_map(fix_edits)
+                .flatten()
+                .collect();
+            if edits.is_empty() {
+                return Err(format!("No fix available for {} on line {}", rule, line));
+            }
+
+            let full_path = Path::new(&project_path).join(&file_path);
+            let mut content = std::fs::read_to_string(&full_path)
+                .map_err(|e| format!("Failed to read file: {}", e))?;
+
+            // Apply from the end of the file backwards so earlier offsets
+            // stay valid, skipping any edit that overlaps one already applied
+            edits.sort_by_key(|edit| std::cmp::Reverse(edit.start));
+            let mut applied_from = content.len();
+            let mut applied = 0;
+            for edit in edits {
+                let start = byte_offset(&content, edit.start);
+                let end = byte_offset(&content, edit.end).max(start);
+                if end > applied_from {
+                    continue;
+                }
+                content.replace_range(start..end, &edit.content);
+                applied_from = start;
+                applied += 1;
+            }
+
+            std::fs::write(&full_path, content)
+                .map_err(|e| format!("Failed to write file: {}", e))?;
+            applied
+        }
+        None => {
+            let before = ruff_check_json(&project_path, &file_path, Some(rule))?.len();
+            let mut cmd = uv_project_command(&project_path);
+            let output = cmd
+                .args(["run", "ruff", "check", &file_path, "--fix", "--no-cache"])
+                .arg(format!("--select={}", rule))
+                .current_dir(&project_path)
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .output()
+                .map_err(|e| format!("Failed to execute uv run ruff fix: {}", e))?;
+            if output.stdout.is_empty() && !output.status.success() {
+                return Err(String::from_utf8_lossy(&output.stderr).to_string());
+            }
+            let after = ruff_check_json(&project_path, &file_path, Some(rule))?.len();
+            before.saturating_sub(after) as u32
+        }
+    };
+
+    let mut result = ruff_check_file(project_path, file_path).await?;
+    result.fixed = fixed;
+    Ok(result)
+}
+
+// Byte offset of the `#` starting a line's comment, ignoring `#` in strings
+fn comment_start(line: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for (offset, c) in line.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' => return Some(offset),
+            None => {}
+        }
+    }
+    None
+}
+
+// Add `rule_code` to the line's noqa directive, creating one if needed.
+// Returns None when the line already suppresses the rule.
+fn add_noqa_to_line(line: &str, rule_code: &str) -> Option<String> {
+    let code = line.trim_end();
+    let comment_at = match comment_start(code) {
+        Some(comment_at) => comment_at,
+        None => return Some(format!("{}  # noqa: {}", code, rule_code)),
+    };
+
+    let comment = &code[comment_at..];
+    let lower = comment.to_ascii_lowercase();
+    let directive = lower
+        .match_indices('#')
+        .map(|(i, _)| i)
+        .find(|&i| lower[i + 1..].trim_start().starts_with("noqa"));
+
+    let hash = match directive {
+        Some(hash) => hash,
+        None => {
+            // Keep the existing comment after the directive
+            return Some(format!(
+                "{}  # noqa: {}  {}",
+                code[..comment_at].trim_end(),
+                rule_code,
+                comment
+            ));
+        }
+    };
+
+    // A bare `# noqa` already suppresses everything on the line
+    let noqa_end = hash + lower[hash..].find("noqa")? + "noqa".len();
+    let codes = comment[noqa_end..].trim_start().strip_prefix(':')?;
+
+    let codes_len = codes
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == ',' || c == ' '))
+        .unwrap_or(codes.len());
+    let listed = codes[..codes_len].trim_end();
+    if listed
+        .split([',', ' '])
+        .any(|listed_code| listed_code.eq_ignore_ascii_case(rule_code))
+    {
+        return None;
+    }
+
+    let insert_at = comment_at + comment.len() - codes.len() + listed.len();
+    let separator = if listed.trim().is_empty() { " " } else { ", " };
+    Some(format!(
+        "{}{}{}{}",
+        &code[..insert_at],
+        separator,
+        rule_code,
+        &code[insert_at..]
+    ))
+}
+
+// Suppress a diagnostic by adding `# noqa: <rule_code>` to its line (1-based),
+// merging into an existing noqa comment. Returns the updated line.
+#[tauri::command]
+pub async fn add_noqa(file_path: String, line: u32, rule_code: String) -> Result<String, String> {
+    let rule_code = rule_code.trim();
+    if rule_code.is_empty() || !rule_code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("Invalid rule code: {}", rule_code));
+    }
+
+    let content =
+        std::fs::read_to_string(&file_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let mut lines: Vec<&str> = content.split('\n').collect();
+    let index = (line as usize)
+        .checked_sub(1)
+        .filter(|&i| i < lines.len())
+        .ok_or_else(|| format!("Line {} is out of range", line))?;
+
+    let original = lines[index];
+    let (text, line_ending) = match original.strip_suffix('\r') {
+        Some(text) => (text, "\r"),
+        None => (original, ""),
+    };
+    let updated = match add_noqa_to_line(text, rule_code) {
+        Some(updated) => updated,
+        None => return Ok(text.to_string()),
+    };
+
+    let replaced = format!("{}{}", updated, line_ending);
+    lines[index] = &replaced;
+    std::fs::write(&file_path, lines.join("\n"))
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+
+    Ok(updated)
+}
+
+#[tauri::command]
+pub async fn start_ruff_watch(
+    window: Window,
+    project_path: String,
+    watch_id: String,
+    watch_manager: State<'_, WatchManager>,
+) -> Result<(), String> {
+    let root = Path::new(&project_path).to_path_buf();
+    if !root.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+
+    let running = watch::register_watch(&watch_manager, &watch_id).await;
+
+    tokio::spawn(async move {
+        let mut snapshot = watch::snapshot_files(&root, "py");
+        let mut pending: Vec<std::path::PathBuf> = Vec::new();
+
+        while running.load(Ordering::SeqCst) {
+            tokio::time::sleep(tokio::time::Duration::from_millis(watch::POLL_INTERVAL_MS)).await;
+
+            let current = watch::snapshot_files(&root, "py");
+            let changed = watch::changed_files(&snapshot, &current);
+            snapshot = current;
+
+            if !changed.is_empty() {
+                // Keep collecting until saves settle down
+                for path in changed {
+                    if !pending.contains(&path) {
+                        pending.push(path);
+                    }
+                }
+                continue;
+            }
+
+            // Lint sequentially so rapid saves never overlap ruff processes
+            for path in pending.drain(..) {
+                if !running.load(Ordering::SeqCst) {
+                    break;
+                }
+                let file_path = path.to_string_lossy().to_string();
+                match ruff_check_file(project_path.clone(), file_path.clone()).await {
+                    Ok(result) => {
+                        let _ = window.emit(
+                            "ruff-diagnostics",
+                            RuffWatchEvent {
+                                watch_id: watch_id.clone(),
+                                file_path,
+                                result,
+                            },
+                        );
+                    }
+                    Err(e) => eprintln!("Ruff watch check failed for {}: {}", file_path, e),
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn stop_ruff_watch(
+    watch_id: String,
+    watch_manager: State<'_, WatchManager>,
+) -> Result<bool, String> {
+    Ok(watch::stop_watch(&watch_manager, &watch_id).await)
+}
+
 #[tauri::command]
 pub async fn create_ruff_config(project_path: String) -> Result<String, String> {
     let config_content = r#"[tool.ruff]
@@ -478,6 +1323,7 @@ exclude = [
     ".svn",
     ".tox",
     ".venv",
+    ".venvs",
     ".vscode",
     "__pypackages__",
     "_build",
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use super::settings::APP_IDENTIFIER;
+
+// Scratch files live outside any project. The scratch directory has no
+// pyproject.toml, so passing it as the project to the `uv run` based run
+// commands runs them with the default Python.
+const SCRATCH_DIR: &str = "scratch";
+
+#[derive(Serialize, Deserialize)]
+pub struct ScratchFile {
+    pub path: String,
+    pub name: String,
+    pub size: u64,
+    // Seconds since the Unix epoch
+    pub modified: u64,
+}
+
+fn scratch_dir() -> Result<PathBuf, String> {
+    dirs::data_dir()
+        .map(|dir| dir.join(APP_IDENTIFIER).join(SCRATCH_DIR))
+        .ok_or_else(|| "Could not determine the app data directory".to_string())
+}
+
+#[tauri::command]
+pub async fn create_scratch_file(extension: String) -> Result<String, String> {
+    let extension = extension.trim().trim_start_matches('.');
+    let extension = if extension.is_empty() {
+        "py"
+    } else {
+        extension
+    };
+    if !extension.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("Invalid file extension: {}", extension));
+    }
+
+    let dir = scratch_dir()?;
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create scratch directory: {}", e))?;
+
+    let stem = format!("scratch_{}", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+    for attempt in 0..100 {
+        let name = if attempt == 0 {
+            format!("{}.{}", stem, extension)
+        } else {
+            format!("{}_{}.{}", stem, attempt, extension)
+        };
+        let path = dir.join(name);
+        // create_new so two files created in the same second never collide
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => return Ok(path.to_string_lossy().to_string()),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(format!("Failed to create scratch file: {}", e)),
+        }
+    }
+    Err("Failed to find a free scratch file name".to_string())
+}
+
+// Newest first
+#[tauri::command]
+pub async fn list_scratch_files() -> Result<Vec<ScratchFile>, String> {
+    let dir = scratch_dir()?;
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read scratch directory: {}", e)),
+    };
+
+    let mut files: Vec<ScratchFile> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let metadata = entry
+                .metadata()
+                .ok()
+                .filter(|metadata| metadata.is_file())?;
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs())
+                .unwrap_or(0);
+            Some(ScratchFile {
+                path: entry.path().to_string_lossy().to_string(),
+                name: entry.file_name().to_string_lossy().to_string(),
+                size: metadata.len(),
+                modified,
+            })
+        })
+        .collect();
+    files.sort_by(|a, b| {
+        b.modified
+            .cmp(&a.modified)
+            .then_with(|| b.name.cmp(&a.name))
+    });
+    Ok(files)
+}
+
+// Only files inside the scratch directory can be deleted this way
+#[tauri::command]
+pub async fn delete_scratch_file(path: String) -> Result<(), String> {
+    let dir = scratch_dir()?
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve scratch directory: {}", e))?;
+    let file = Path::new(&path)
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", path, e))?;
+    if file.parent() != Some(dir.as_path()) || !file.is_file() {
+        return Err(format!("Not a scratch file: {}", path));
+    }
+
+    fs::remove_file(&file).map_err(|e| format!("Failed to delete scratch file: {}", e))
+}
@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use super::file::find_files_with_extension;
+use super::git::ignored_paths;
+use super::symbols::import_statements;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TodoItem {
+    pub file: String,
+    pub line: u32,
+    pub tag: String,
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ImportLocation {
+    pub file: String,
+    pub line: u32,
+}
+
+const DEFAULT_TODO_TAGS: &[&str] = &["TODO", "FIXME", "HACK", "XXX"];
+
+#[tauri::command]
+pub async fn scan_todos(
+    project_path: String,
+    tags: Option<Vec<String>>,
+) -> Result<Vec<TodoItem>, String> {
+    let tags: Vec<String> = match tags {
+        Some(tags) if !tags.is_empty() => tags,
+        _ => DEFAULT_TODO_TAGS.iter().map(|t| t.to_string()).collect(),
+    };
+
+    let mut todos = Vec::new();
+    for file in project_python_files(&project_path) {
+        let content = match fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        let mut in_triple_quote = None;
+        for (index, line) in content.lines().enumerate() {
+            let comment = match comment_start(line, &mut in_triple_quote) {
+                Some(pos) => &line[pos + 1..],
+                None => continue,
+            };
+
+            if let Some((tag, text)) = find_tag(comment, &tags) {
+                todos.push(TodoItem {
+                    file: file.clone(),
+                    line: index as u32 + 1,
+                    tag,
+                    text,
+                });
+            }
+        }
+    }
+
+    Ok(todos)
+}
+
+// Files importing `module_name` or one of its submodules, including
+// `from package import module` when `module_name` is `package.module`
+#[tauri::command]
+pub async fn find_importers(
+    project_path: String,
+    module_name: String,
+) -> Result<Vec<ImportLocation>, String> {
+    let module_name = module_name.trim();
+    if module_name.is_empty() {
+        return Err("Module name cannot be empty".to_string());
+    }
+    let is_target = |module: &str| {
+        module == module_name
+            || module
+                .strip_prefix(module_name)
+                .map_or(false, |rest| rest.starts_with('.'))
+    };
+
+    let mut importers = Vec::new();
+    for file in project_python_files(&project_path) {
+        let content = match fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        for import in import_statements(&content) {
+            let imports_target = is_target(&import.module)
+                || import
+                    .names
+                    .iter()
+                    .any(|name| is_target(&format!("{}.{}", import.module, name)));
+            if imports_target {
+                importers.push(ImportLocation {
+                    file: file.clone(),
+                    line: import.line,
+                });
+            }
+        }
+    }
+
+    Ok(importers)
+}
+
+// All .py files in the project, minus virtualenvs and gitignored paths
+fn project_python_files(project_path: &str) -> Vec<String> {
+    let files: Vec<String> = find_files_with_extension(Path::new(project_path), "py")
+        .into_iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+
+    let ignored: HashSet<String> = ignored_paths(project_path, &files).into_iter().collect();
+    files.into_iter().filter(|f| !ignored.contains(f)).collect()
+}
+
+// Byte offset of the `#` starting a comment on this line, skipping any `#`
+// inside string literals. Tracks triple-quoted strings spanning lines.
+fn comment_start(line: &str, in_triple_quote: &mut Option<&'static [u8]>) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    let mut in_string: Option<u8> = None;
+
+    while i < bytes.len() {
+        if let Some(delimiter) = *in_triple_quote {
+            match bytes[i..].windows(3).position(|w| w == delimiter) {
+                Some(end) => {
+                    i += end + 3;
+                    *in_triple_quote = None;
+                    continue;
+                }
+                None => return None,
+            }
+        }
+
+        let c = bytes[i];
+        if let Some(quote) = in_string {
+            if c == b'\\' {
+                i += 2;
+                continue;
+            }
+            if c == quote {
+                in_string = None;
+            }
+        } else if c == b'#' {
+            return Some(i);
+        } else if bytes[i..].starts_with(b"\"\"\"") || bytes[i..].starts_with(b"'''") {
+            *in_triple_quote = Some(if c == b'"' { b"\"\"\"" } else { b"'''" });
+            i += 3;
+            continue;
+        } else if c == b'"' || c == b'\'' {
+            in_string = Some(c);
+        }
+        i += 1;
+    }
+
+    None
+}
+
+// Find the first tag appearing as a whole word in the comment text
+fn find_tag(comment: &str, tags: &[String]) -> Option<(String, String)> {
+    tags.iter().find_map(|tag| {
+        let mut search_from = 0;
+        while let Some(pos) = comment[search_from..].find(tag.as_str()) {
+            let start = search_from + pos;
+            let end = start + tag.len();
+            let before_ok = comment[..start]
+                .chars()
+                .next_back()
+                .map_or(true, |c| !c.is_alphanumeric() && c != '_');
+            let after_ok = comment[end..]
+                .chars()
+                .next()
+                .map_or(true, |c| !c.is_alphanumeric() && c != '_');
+
+            if before_ok && after_ok {
+                let text = comment[end..]
+                    .trim_start_matches(|c: char| c == ':' || c.is_whitespace())
+                    .trim_end()
+                    .to_string();
+                return Some((tag.clone(), text));
+            }
+            search_from = end;
+        }
+        None
+    })
+}
@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// Matches `identifier` in tauri.conf.json, so settings live in the same
+// directory Tauri resolves as the app config dir
+pub(crate) const APP_IDENTIFIER: &str = "com.pyra.ide";
+const SETTINGS_FILE: &str = "settings.json";
+const RECENT_FILES_FILE: &str = "recent_files.json";
+const MAX_RECENT_FILES: usize = 50;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct AppSettings {
+    pub theme: String,
+    pub default_python_version: Option<String>,
+    pub default_template: Option<String>,
+    // Custom uv binary, for installs that aren't on the app's PATH
+    pub uv_path: Option<String>,
+    pub terminal_shell: Option<String>,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            theme: "catppuccin-mocha".to_string(),
+            default_python_version: None,
+            default_template: None,
+            uv_path: None,
+            terminal_shell: None,
+        }
+    }
+}
+
+fn config_file(name: &str) -> Result<PathBuf, String> {
+    dirs::config_dir()
+        .map(|dir| dir.join(APP_IDENTIFIER).join(name))
+        .ok_or_else(|| "Could not determine the config directory".to_string())
+}
+
+fn settings_path() -> Result<PathBuf, String> {
+    config_file(SETTINGS_FILE)
+}
+
+// Missing or unreadable settings fall back to the defaults
+pub(crate) fn load_app_settings() -> AppSettings {
+    settings_path()
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// The configured uv binary, or plain `uv` to be looked up on PATH
+pub(crate) fn uv_program() -> String {
+    load_app_settings()
+        .uv_path
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .unwrap_or_else(|| "uv".to_string())
+}
+
+// A `uv` command using the configured binary, without a console window on
+// Windows
+pub(crate) fn uv_command() -> Command {
+    #[cfg_attr(not(target_os = "windows"), allow(unused_mut))]
+    let mut cmd = Command::new(uv_program());
+    #[cfg(target_os = "windows")]
+    {
+        cmd.creation_flags(0x08000000);
+    }
+    cmd
+}
+
+#[tauri::command]
+pub async fn get_app_settings() -> Result<AppSettings, String> {
+    Ok(load_app_settings())
+}
+
+#[tauri::command]
+pub async fn save_app_settings(settings: AppSettings) -> Result<(), String> {
+    write_app_settings(&settings)
+}
+
+fn write_app_settings(settings: &AppSettings) -> Result<(), String> {
+    write_config_file(&settings_path()?, settings)
+}
+
+fn write_config_file(path: &Path, value: &impl Serialize) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", path.display(), e))?;
+    fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UvDiagnosis {
+    pub on_path: bool,
+    // Where uv was found, if anywhere
+    pub found_path: Option<String>,
+    pub searched: Vec<String>,
+    pub persisted: bool,
+}
+
+const UV_EXE: &str = if cfg!(target_os = "windows") {
+    "uv.exe"
+} else {
+    "uv"
+};
+
+// Directories the uv installers, cargo and Homebrew put uv in. Apps launched
+// from Finder or the Dock don't see PATH changes made in shell profiles.
+fn uv_install_dirs() -> Vec<PathBuf> {
+    let mut dirs_to_search = Vec::new();
+    if let Some(home) = dirs::home_dir() {
+        dirs_to_search.push(home.join(".local").join("bin"));
+        dirs_to_search.push(home.join(".cargo").join("bin"));
+    }
+    if cfg!(target_os = "windows") {
+        if let Some(local) = dirs::data_local_dir() {
+            dirs_to_search.push(local.join("Programs").join("uv"));
+        }
+    } else {
+        dirs_to_search.push(PathBuf::from("/opt/homebrew/bin"));
+        dirs_to_search.push(PathBuf::from("/usr/local/bin"));
+        dirs_to_search.push(PathBuf::from("/home/linuxbrew/.linuxbrew/bin"));
+    }
+    dirs_to_search
+}
+
+// Find uv when it isn't on PATH, optionally saving the location as `uv_path`
+#[tauri::command]
+pub async fn diagnose_uv(persist: Option<bool>) -> Result<UvDiagnosis, String> {
+    let path_dirs: Vec<PathBuf> = std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).collect())
+        .unwrap_or_default();
+    if path_dirs.iter().any(|dir| dir.join(UV_EXE).is_file()) {
+        return Ok(UvDiagnosis {
+            on_path: true,
+            found_path: None,
+            searched: Vec::new(),
+            persisted: false,
+        });
+    }
+
+    let mut searched = Vec::new();
+    let mut settings = load_app_settings();
+    if let Some(configured) = settings.uv_path.as_ref().filter(|p| !p.trim().is_empty()) {
+        searched.push(configured.clone());
+        if Path::new(configured).is_file() {
+            return Ok(UvDiagnosis {
+                on_path: false,
+                found_path: Some(configured.clone()),
+                searched,
+                persisted: false,
+            });
+        }
+    }
+
+    let mut found_path = None;
+    for dir in uv_install_dirs() {
+        let candidate = dir.join(UV_EXE);
+        searched.push(candidate.display().to_string());
+        if candidate.is_file() {
+            found_path = Some(candidate.display().to_string());
+            break;
+        }
+    }
+
+    let mut persisted = false;
+    if let (Some(found), true) = (&found_path, persist.unwrap_or(false)) {
+        settings.uv_path = Some(found.clone());
+        write_app_settings(&settings)?;
+        persisted = true;
+    }
+
+    Ok(UvDiagnosis {
+        on_path: false,
+        found_path,
+        searched,
+        persisted,
+    })
+}
+
+// Recently opened files across all projects, most recent first
+fn read_recent_files(path: &Path) -> Vec<String> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+pub async fn add_recent_file(path: String) -> Result<(), String> {
+    let recent_path = config_file(RECENT_FILES_FILE)?;
+    let mut recent = read_recent_files(&recent_path);
+
+    recent.retain(|existing| existing != &path);
+    recent.insert(0, path);
+    recent.truncate(MAX_RECENT_FILES);
+
+    write_config_file(&recent_path, &recent)
+}
+
+// Files that no longer exist are dropped from the list
+#[tauri::command]
+pub async fn get_recent_files(limit: Option<usize>) -> Result<Vec<String>, String> {
+    let recent_path = config_file(RECENT_FILES_FILE)?;
+    let recent = read_recent_files(&recent_path);
+
+    let existing: Vec<String> = recent
+        .iter()
+        .filter(|path| Path::new(path).is_file())
+        .cloned()
+        .collect();
+    if existing.len() != recent.len() {
+        write_config_file(&recent_path, &existing)?;
+    }
+
+    Ok(existing
+        .into_iter()
+        .take(limit.unwrap_or(MAX_RECENT_FILES))
+        .collect())
+}
@@ -0,0 +1,1243 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: String,
+    pub line: u32,
+    pub column: u32,
+    pub children: Vec<Symbol>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TextEdit {
+    pub line: u32,
+    pub start_col: u32,
+    pub end_col: u32,
+    pub new_text: String,
+}
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+// One `import` or `from ... import` statement
+pub(crate) struct ImportStatement {
+    // Dotted module path; relative imports keep their leading dots
+    pub module: String,
+    // Names taken from the module by `from ... import`, without aliases
+    pub names: Vec<String>,
+    pub line: u32,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TokenKind {
+    Name,
+    Number,
+    String,
+    Op,
+}
+
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    // 1-based line and column (in characters)
+    line: u32,
+    column: u32,
+    // Set on the first token of a logical line
+    starts_line: bool,
+}
+
+// Split Python source into names, numbers, strings and operators, dropping
+// comments and whitespace. Lines continued by brackets or a backslash belong to
+// the same logical line.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut line = 1;
+    let mut line_start = 0;
+    let mut bracket_depth = 0usize;
+    let mut at_line_start = true;
+
+    let column_at =
+        |line_start: usize, pos: usize| source[line_start..pos].chars().count() as u32 + 1;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let start = i;
+
+        match c {
+            b'\n' => {
+                i += 1;
+                line += 1;
+                line_start = i;
+                if bracket_depth == 0 {
+                    at_line_start = true;
+                }
+                continue;
+            }
+            b' ' | b'\t' | b'\r' | b'\x0c' => {
+                i += 1;
+                continue;
+            }
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            b'\\' if bytes.get(i + 1) == Some(&b'\n') || bytes[i + 1..].starts_with(b"\r\n") => {
+                // Explicit line continuation
+                i += if bytes[i + 1] == b'\n' { 2 } else { 3 };
+                line += 1;
+                line_start = i;
+                continue;
+            }
+            _ => {}
+        }
+
+        let token_line = line;
+        let token_column = column_at(line_start, start);
+        let kind;
+
+        if c.is_ascii_alphabetic() || c == b'_' || c >= 0x80 {
+            while i < bytes.len()
+                && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] >= 0x80)
+            {
+                i += 1;
+            }
+            let word = &source[start..i];
+            let is_prefix = word.len() <= 2
+                && word
+                    .chars()
+                    .all(|ch| matches!(ch.to_ascii_lowercase(), 'r' | 'b' | 'f' | 'u'));
+            if is_prefix && i < bytes.len() && (bytes[i] == b'"' || bytes[i] == b'\'') {
+                let (end, lines, last_line_start) = skip_string(bytes, i);
+                i = end;
+                if lines > 0 {
+                    line += lines;
+                    line_start = last_line_start;
+                }
+                kind = TokenKind::String;
+            } else {
+                kind = TokenKind::Name;
+            }
+        } else if c == b'"' || c == b'\'' {
+            let (end, lines, last_line_start) = skip_string(bytes, i);
+            i = end;
+            if lines > 0 {
+                line += lines;
+                line_start = last_line_start;
+            }
+            kind = TokenKind::String;
+        } else if c.is_ascii_digit()
+            || (c == b'.' && bytes.get(i + 1).map_or(false, |b| b.is_ascii_digit()))
+        {
+            while i < bytes.len()
+                && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] == b'.')
+            {
+                i += 1;
+            }
+            kind = TokenKind::Number;
+        } else {
+            match c {
+                b'(' | b'[' | b'{' => bracket_depth += 1,
+                b')' | b']' | b'}' => bracket_depth = bracket_depth.saturating_sub(1),
+                _ => {}
+            }
+            // Operators are only compared against single characters
+            i += 1;
+            kind = TokenKind::Op;
+        }
+
+        tokens.push(Token {
+            kind,
+            text: &source[start..i],
+            line: token_line,
+            column: token_column,
+            starts_line: at_line_start,
+        });
+        at_line_start = false;
+    }
+
+    tokens
+}
+
+// Skip a string literal starting at its opening quote. Returns the end offset,
+// the number of newlines crossed and the offset where the last line began.
+fn skip_string(bytes: &[u8], start: usize) -> (usize, u32, usize) {
+    let quote = bytes[start];
+    let triple = bytes[start..].starts_with(&[quote; 3]);
+    let mut i = start + if triple { 3 } else { 1 };
+    let mut lines = 0;
+    let mut last_line_start = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b'\\' {
+            let escaped = if bytes[i + 1..].starts_with(b"\r\n") {
+                3
+            } else {
+                2
+            };
+            if bytes.get(i + escaped - 1) == Some(&b'\n') {
+                lines += 1;
+                last_line_start = i + escaped;
+            }
+            i += escaped;
+            continue;
+        }
+        if c == b'\n' {
+            if !triple {
+                // Unterminated single-quoted string
+                return (i, lines, last_line_start);
+            }
+            lines += 1;
+            last_line_start = i + 1;
+        }
+        if c == quote {
+            if !triple {
+                return (i + 1, lines, last_line_start);
+            }
+            if bytes[i..].starts_with(&[quote; 3]) {
+                return (i + 3, lines, last_line_start);
+            }
+        }
+        i += 1;
+    }
+
+    (bytes.len(), lines, last_line_start)
+}
+
+// All import statements in the source, including ones inside functions and
+// ones wrapped across lines in parentheses. `import a, b` yields one entry
+// per module.
+pub(crate) fn import_statements(source: &str) -> Vec<ImportStatement> {
+    let tokens = tokenize(source);
+    let mut imports = Vec::new();
+    let mut index = 0;
+
+    while index < tokens.len() {
+        let start = &tokens[index];
+        index += 1;
+        if !start.starts_line || start.kind != TokenKind::Name {
+            continue;
+        }
+        let statement_end = tokens[index..]
+            .iter()
+            .position(|t| t.starts_line)
+            .map_or(tokens.len(), |offset| index + offset);
+        let statement = &tokens[index..statement_end];
+
+        match start.text {
+            "import" => {
+                for part in statement.split(|t| t.kind == TokenKind::Op && t.text == ",") {
+                    let module = dotted_name(part);
+                    if !module.is_empty() {
+                        imports.push(ImportStatement {
+                            module,
+                            names: Vec::new(),
+                            line: start.line,
+                        });
+                    }
+                }
+            }
+            "from" => {
+                let import_at = match statement
+                    .iter()
+                    .position(|t| t.kind == TokenKind::Name && t.text == "import")
+                {
+                    Some(position) => position,
+                    None => continue,
+                };
+                let names = statement[import_at + 1..]
+                    .split(|t| t.kind == TokenKind::Op && t.text == ",")
+                    .filter_map(|part| {
+                        part.iter()
+                            .find(|t| t.kind == TokenKind::Name || t.text == "*")
+                            .map(|t| t.text.to_string())
+                    })
+                    .collect();
+                imports.push(ImportStatement {
+                    module: dotted_name(&statement[..import_at]),
+                    names,
+                    line: start.line,
+                });
+            }
+            _ => {}
+        }
+        index = statement_end;
+    }
+
+    imports
+}
+
+// The dotted name at the start of the tokens, stopping at `as` or anything
+// that isn't part of a name
+fn dotted_name(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .take_while(|t| {
+            (t.kind == TokenKind::Name && t.text != "as")
+                || (t.kind == TokenKind::Op && t.text == ".")
+        })
+        .map(|t| t.text)
+        .collect()
+}
+
+// Lines where a statement starts, i.e. where the debugger can stop. Header
+// lines without code of their own (`else:`, `try:`, `finally:`) and bare
+// string statements such as docstrings are left out.
+pub(crate) fn executable_lines(source: &str) -> Vec<u32> {
+    let tokens = tokenize(source);
+    let mut lines = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        if !token.starts_line {
+            continue;
+        }
+        let statement_end = tokens[index + 1..]
+            .iter()
+            .position(|t| t.starts_line)
+            .map_or(tokens.len(), |offset| index + 1 + offset);
+        let statement = &tokens[index..statement_end];
+
+        let bare_header = token.kind == TokenKind::Name
+            && matches!(token.text, "else" | "try" | "finally")
+            && statement.get(1).map_or(false, |t| t.text == ":");
+        let only_strings = statement.iter().all(|t| t.kind == TokenKind::String);
+        if !bare_header && !only_strings && lines.last() != Some(&token.line) {
+            lines.push(token.line);
+        }
+    }
+
+    lines
+}
+
+#[tauri::command]
+pub async fn get_document_symbols(path: String) -> Result<Vec<Symbol>, String> {
+    let source = fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+    Ok(document_symbols(&source))
+}
+
+fn document_symbols(source: &str) -> Vec<Symbol> {
+    let tokens = tokenize(source);
+    let mut roots: Vec<Symbol> = Vec::new();
+    // Open definitions with the indentation of their `def`/`class` line
+    let mut stack: Vec<(u32, Symbol)> = Vec::new();
+
+    let close_until = |stack: &mut Vec<(u32, Symbol)>, roots: &mut Vec<Symbol>, indent: u32| {
+        while stack.last().map_or(false, |(top, _)| *top >= indent) {
+            let (_, symbol) = stack.pop().unwrap();
+            match stack.last_mut() {
+                Some((_, parent)) => parent.children.push(symbol),
+                None => roots.push(symbol),
+            }
+        }
+    };
+
+    for (index, token) in tokens.iter().enumerate() {
+        if !token.starts_line {
+            continue;
+        }
+        // Any statement closes definitions indented at least as deep
+        close_until(&mut stack, &mut roots, token.column);
+
+        let mut keyword_index = index;
+        if token.text == "async" {
+            keyword_index += 1;
+        }
+        let keyword = match tokens.get(keyword_index) {
+            Some(t) if t.kind == TokenKind::Name && (t.text == "def" || t.text == "class") => {
+                t.text
+            }
+            _ => continue,
+        };
+        let name = match tokens.get(keyword_index + 1) {
+            Some(t) if t.kind == TokenKind::Name => t,
+            _ => continue,
+        };
+
+        let inside_class = stack
+            .last()
+            .map_or(false, |(_, parent)| parent.kind == "class");
+        let kind = match keyword {
+            "class" => "class",
+            _ if inside_class => "method",
+            _ => "function",
+        };
+
+        stack.push((
+            token.column,
+            Symbol {
+                name: name.text.to_string(),
+                kind: kind.to_string(),
+                line: name.line,
+                column: name.column,
+                children: Vec::new(),
+            },
+        ));
+    }
+
+    close_until(&mut stack, &mut roots, 0);
+    roots
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ScopeKind {
+    Module,
+    Function,
+    Class,
+    Comprehension,
+}
+
+struct Scope<'a> {
+    kind: ScopeKind,
+    parent: Option<usize>,
+    // Names bound anywhere in the scope, which makes them local to all of it
+    bound: HashSet<&'a str>,
+    globals: HashSet<&'a str>,
+    nonlocals: HashSet<&'a str>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum NameRole {
+    // Keywords, imported module paths and other names that never get renamed
+    Other,
+    Reference,
+    Binding,
+    // `name` in `import name` or `from module import name`, bound without an alias
+    Import,
+    // `name` in `obj.name`
+    Attribute,
+    // `name` in `call(name=...)`
+    Keyword,
+}
+
+// The scope every token is evaluated in and the role of every name, following
+// Python's rules: a name bound anywhere in a function is local to it unless
+// declared `global`/`nonlocal`, and class bodies are invisible to the
+// functions nested in them.
+struct ScopeMap<'a> {
+    scopes: Vec<Scope<'a>>,
+    scope_of: Vec<usize>,
+    roles: Vec<NameRole>,
+}
+
+impl<'a> ScopeMap<'a> {
+    // The scope that binds `name` as seen from `scope`, or None when a
+    // `nonlocal` declaration has nothing to refer to
+    fn resolve(&self, scope: usize, name: &str) -> Option<usize> {
+        let current = &self.scopes[scope];
+        if current.globals.contains(name) {
+            return Some(0);
+        }
+        if current.nonlocals.contains(name) {
+            return self.enclosing(scope, name).filter(|&found| found != 0);
+        }
+        if current.bound.contains(name) {
+            return Some(scope);
+        }
+        self.enclosing(scope, name)
+    }
+
+    // Look a free name up in the scopes around `scope`, skipping class bodies
+    fn enclosing(&self, scope: usize, name: &str) -> Option<usize> {
+        let mut parent = self.scopes[scope].parent;
+        while let Some(index) = parent {
+            let candidate = &self.scopes[index];
+            if index == 0 {
+                break;
+            }
+            if candidate.kind != ScopeKind::Class
+                && (candidate.bound.contains(name)
+                    || candidate.globals.contains(name)
+                    || candidate.nonlocals.contains(name))
+            {
+                return self.resolve(index, name);
+            }
+            parent = candidate.parent;
+        }
+        Some(0)
+    }
+}
+
+// What may precede `=` in an assignment; anything else makes it a comparison
+const AUGMENTED_OPERATORS: &[&str] = &[
+    "", "+", "-", "*", "/", "%", "&", "|", "^", "@", "**", "//", "<<", ">>",
+];
+
+struct ScopeBuilder<'t, 'a> {
+    tokens: &'t [Token<'a>],
+    map: ScopeMap<'a>,
+}
+
+fn scope_map<'a>(tokens: &[Token<'a>]) -> ScopeMap<'a> {
+    let mut builder = ScopeBuilder {
+        tokens,
+        map: ScopeMap {
+            scopes: Vec::new(),
+            scope_of: vec![0; tokens.len()],
+            roles: vec![NameRole::Other; tokens.len()],
+        },
+    };
+    builder.new_scope(ScopeKind::Module, None);
+
+    let starts: Vec<usize> = (0..tokens.len())
+        .filter(|&index| index == 0 || tokens[index].starts_line)
+        .collect();
+    // Open `def`/`class` bodies with the indentation of their header
+    let mut blocks: Vec<(u32, usize)> = Vec::new();
+    for (position, &start) in starts.iter().enumerate() {
+        let end = starts.get(position + 1).copied().unwrap_or(tokens.len());
+        let indent = tokens[start].column;
+        while blocks.last().map_or(false, |(top, _)| *top >= indent) {
+            blocks.pop();
+        }
+        let scope = blocks.last().map_or(0, |(_, scope)| *scope);
+        if let Some(body) = builder.statement(start, end, scope) {
+            blocks.push((indent, body));
+        }
+    }
+
+    builder.map
+}
+
+impl<'t, 'a> ScopeBuilder<'t, 'a> {
+    fn new_scope(&mut self, kind: ScopeKind, parent: Option<usize>) -> usize {
+        self.map.scopes.push(Scope {
+            kind,
+            parent,
+            bound: HashSet::new(),
+            globals: HashSet::new(),
+            nonlocals: HashSet::new(),
+        });
+        self.map.scopes.len() - 1
+    }
+
+    fn is_op(&self, index: usize, op: &str) -> bool {
+        self.tokens
+            .get(index)
+            .map_or(false, |t| t.kind == TokenKind::Op && t.text == op)
+    }
+
+    fn is_name(&self, index: usize, text: &str) -> bool {
+        self.tokens
+            .get(index)
+            .map_or(false, |t| t.kind == TokenKind::Name && t.text == text)
+    }
+
+    fn is_identifier_at(&self, index: usize) -> bool {
+        self.tokens.get(index).map_or(false, |t| {
+            t.kind == TokenKind::Name && !PYTHON_KEYWORDS.contains(&t.text)
+        })
+    }
+
+    // Bracket depth change caused by the token at `index`
+    fn depth_change(&self, index: usize) -> i32 {
+        let token = &self.tokens[index];
+        match (token.kind, token.text) {
+            (TokenKind::Op, "(") | (TokenKind::Op, "[") | (TokenKind::Op, "{") => 1,
+            (TokenKind::Op, ")") | (TokenKind::Op, "]") | (TokenKind::Op, "}") => -1,
+            _ => 0,
+        }
+    }
+
+    // Index of the bracket closing the one at `open`, or `end` if unclosed
+    fn matching_close(&self, open: usize, end: usize) -> usize {
+        let mut depth = 0;
+        for index in open..end {
+            depth += self.depth_change(index);
+            if depth == 0 {
+                return index;
+            }
+        }
+        end
+    }
+
+    // Indexes in `start..end` outside any bracket nested in the range
+    fn top_level(&self, start: usize, end: usize) -> Vec<usize> {
+        let mut depth = 0;
+        let mut indexes = Vec::new();
+        for index in start..end {
+            let change = self.depth_change(index);
+            if change < 0 {
+                depth += change;
+            }
+            if depth == 0 {
+                indexes.push(index);
+            }
+            if change > 0 {
+                depth += change;
+            }
+        }
+        indexes
+    }
+
+    // Split `start..end` at top-level occurrences of the operator `separator`
+    fn split(&self, start: usize, end: usize, separator: &str) -> Vec<(usize, usize)> {
+        let mut parts = Vec::new();
+        let mut part_start = start;
+        for index in self.top_level(start, end) {
+            if self.is_op(index, separator) {
+                parts.push((part_start, index));
+                part_start = index + 1;
+            }
+        }
+        parts.push((part_start, end));
+        parts
+    }
+
+    // The colon ending a header or lambda parameter list, or `end` if none.
+    // Colons of nested lambdas and `:=` are skipped.
+    fn find_colon(&self, start: usize, end: usize) -> usize {
+        let mut lambdas = 0;
+        for index in self.top_level(start, end) {
+            if self.is_name(index, "lambda") {
+                lambdas += 1;
+            } else if self.is_op(index, ":") && !self.is_op(index + 1, "=") {
+                if lambdas == 0 {
+                    return index;
+                }
+                lambdas -= 1;
+            }
+        }
+        end
+    }
+
+    fn find_name(&self, start: usize, end: usize, text: &str) -> usize {
+        self.top_level(start, end)
+            .into_iter()
+            .find(|&index| self.is_name(index, text))
+            .unwrap_or(end)
+    }
+
+    fn bind(&mut self, index: usize, scope: usize) {
+        let name = self.tokens[index].text;
+        self.map.scope_of[index] = scope;
+        self.map.roles[index] = NameRole::Binding;
+        let current = &self.map.scopes[scope];
+        if current.globals.contains(name) {
+            self.map.scopes[0].bound.insert(name);
+        } else if !current.nonlocals.contains(name) {
+            self.map.scopes[scope].bound.insert(name);
+        }
+    }
+
+    // One logical line. Returns the scope of the body when it opens a
+    // `def` or `class`.
+    fn statement(&mut self, start: usize, end: usize, scope: usize) -> Option<usize> {
+        let mut keyword = start;
+        if self.is_name(keyword, "async") {
+            keyword += 1;
+        }
+        if (self.is_name(keyword, "def") || self.is_name(keyword, "class"))
+            && self.is_identifier_at(keyword + 1)
+        {
+            return Some(self.definition(keyword, end, scope));
+        }
+        self.simple_statements(start, end, scope);
+        None
+    }
+
+    fn definition(&mut self, keyword: usize, end: usize, scope: usize) -> usize {
+        let is_class = self.is_name(keyword, "class");
+        self.bind(keyword + 1, scope);
+        let kind = if is_class {
+            ScopeKind::Class
+        } else {
+            ScopeKind::Function
+        };
+        let body = self.new_scope(kind, Some(scope));
+
+        let mut index = keyword + 2;
+        if self.is_op(index, "(") {
+            let close = self.matching_close(index, end);
+            if is_class {
+                self.expression(index + 1, close, scope);
+            } else {
+                self.parameters(index + 1, close, scope, body);
+            }
+            index = close + 1;
+        }
+        // Return annotation, then a body on the same line if there is one
+        let colon = self.find_colon(index.min(end), end);
+        self.expression(index.min(colon), colon, scope);
+        if colon < end {
+            self.simple_statements(colon + 1, end, body);
+        }
+        body
+    }
+
+    // Parameter names are bound in `inner`; defaults and annotations are
+    // evaluated in `outer`
+    fn parameters(&mut self, start: usize, end: usize, outer: usize, inner: usize) {
+        for (part_start, part_end) in self.split(start, end, ",") {
+            let mut index = part_start;
+            while index < part_end && (self.is_op(index, "*") || self.is_op(index, "/")) {
+                index += 1;
+            }
+            if index < part_end && self.is_identifier_at(index) {
+                self.bind(index, inner);
+                index += 1;
+            }
+            self.expression(index, part_end, outer);
+        }
+    }
+
+    fn simple_statements(&mut self, start: usize, end: usize, scope: usize) {
+        for (part_start, part_end) in self.split(start, end, ";") {
+            if part_start < part_end {
+                self.simple_statement(part_start, part_end, scope);
+            }
+        }
+    }
+
+    fn simple_statement(&mut self, start: usize, end: usize, scope: usize) {
+        let mut first = start;
+        if self.is_name(first, "async") {
+            first += 1;
+        }
+        let keyword = match self.tokens.get(first) {
+            Some(t) if t.kind == TokenKind::Name => t.text,
+            _ => "",
+        };
+        match keyword {
+            "global" | "nonlocal" => {
+                for index in first + 1..end {
+                    if self.is_identifier_at(index) {
+                        let name = self.tokens[index].text;
+                        let current = &mut self.map.scopes[scope];
+                        if keyword == "global" {
+                            current.globals.insert(name);
+                        } else {
+                            current.nonlocals.insert(name);
+                        }
+                        self.map.scope_of[index] = scope;
+                        self.map.roles[index] = NameRole::Reference;
+                    }
+                }
+            }
+            "import" => self.import_names(first + 1, end, scope),
+            "from" => {
+                let import = self.find_name(first, end, "import");
+                self.import_names(import + 1, end, scope);
+            }
+            "del" => self.target(first + 1, end, scope),
+            "for" => {
+                let colon = self.find_colon(first, end);
+                let in_at = self.find_name(first, colon, "in");
+                self.target(first + 1, in_at, scope);
+                self.expression(in_at, colon, scope);
+                self.body(colon, end, scope);
+            }
+            "with" | "except" => {
+                let colon = self.find_colon(first, end);
+                for (part_start, part_end) in self.split(first + 1, colon, ",") {
+                    let as_at = self.find_name(part_start, part_end, "as");
+                    self.expression(part_start, as_at, scope);
+                    if as_at < part_end {
+                        self.target(as_at + 1, part_end, scope);
+                    }
+                }
+                self.body(colon, end, scope);
+            }
+            "if" | "elif" | "else" | "while" | "try" | "finally" => {
+                let colon = self.find_colon(first, end);
+                self.expression(first, colon, scope);
+                self.body(colon, end, scope);
+            }
+            // Soft keywords: `match = ...` or `case(...)` are ordinary statements
+            "match" | "case" if self.is_compound_header(first, end) => {
+                let colon = self.find_colon(first, end);
+                if keyword == "match" {
+                    self.expression(first + 1, colon, scope);
+                } else {
+                    let guard = self.find_name(first, colon, "if");
+                    self.pattern(first + 1, guard, scope);
+                    self.expression(guard, colon, scope);
+                }
+                self.body(colon, end, scope);
+            }
+            _ => self.assignment(start, end, scope),
+        }
+    }
+
+    // Whether the statement starting at the soft keyword `first` is a `match` or
+    // `case` header rather than an assignment or expression using the name
+    fn is_compound_header(&self, first: usize, end: usize) -> bool {
+        let uses_name = self.tokens.get(first + 1).map_or(true, |next| {
+            next.kind == TokenKind::Op && matches!(next.text, "=" | "." | ":")
+        });
+        !uses_name && first + 1 < end && self.find_colon(first, end) < end
+    }
+
+    // Statements after a compound statement's colon on the same line
+    fn body(&mut self, colon: usize, end: usize, scope: usize) {
+        if colon < end {
+            self.simple_statements(colon + 1, end, scope);
+        }
+    }
+
+    // The names after `import`, with or without `as` aliases
+    fn import_names(&mut self, start: usize, end: usize, scope: usize) {
+        let (start, end) = if self.is_op(start, "(") {
+            (start + 1, self.matching_close(start, end))
+        } else {
+            (start, end)
+        };
+        for (part_start, part_end) in self.split(start, end, ",") {
+            let as_at = self.find_name(part_start, part_end, "as");
+            if as_at < part_end {
+                if self.is_identifier_at(as_at + 1) {
+                    self.bind(as_at + 1, scope);
+                }
+            } else if self.is_identifier_at(part_start) {
+                self.bind(part_start, scope);
+                self.map.roles[part_start] = NameRole::Import;
+            }
+        }
+    }
+
+    // Expression statements and plain, augmented and annotated assignments
+    fn assignment(&mut self, start: usize, end: usize, scope: usize) {
+        let mut value_start = start;
+        for index in self.top_level(start, end) {
+            // Anything after a lambda is its body, not an assignment
+            if self.is_name(index, "lambda") {
+                break;
+            }
+            if self.is_op(index, ":") && !self.is_op(index + 1, "=") && value_start == start {
+                self.target(start, index, scope);
+                self.expression(index + 1, end, scope);
+                return;
+            }
+            if !self.is_op(index, "=") || self.is_op(index + 1, "=") {
+                continue;
+            }
+            // Walk back over the operator of an augmented assignment
+            let mut target_end = index;
+            while target_end > value_start
+                && self.tokens[target_end - 1].kind == TokenKind::Op
+                && "+-*/%&|^@<>=!:".contains(self.tokens[target_end - 1].text)
+            {
+                target_end -= 1;
+            }
+            let operator: String = self.tokens[target_end..index]
+                .iter()
+                .map(|t| t.text)
+                .collect();
+            if AUGMENTED_OPERATORS.contains(&operator.as_str()) {
+                self.target(value_start, target_end, scope);
+                value_start = index + 1;
+            }
+        }
+        self.expression(value_start, end, scope);
+    }
+
+    // Assignment targets: plain names are bound, while anything subscripted,
+    // called or used as an attribute base is only referenced
+    fn target(&mut self, start: usize, end: usize, scope: usize) {
+        let mut index = start;
+        while index < end {
+            let token = &self.tokens[index];
+            self.map.scope_of[index] = scope;
+            if self.is_identifier_at(index) {
+                if index > start && self.is_op(index - 1, ".") {
+                    self.map.roles[index] = NameRole::Attribute;
+                } else if index + 1 < end
+                    && (self.is_op(index + 1, ".")
+                        || self.is_op(index + 1, "[")
+                        || self.is_op(index + 1, "("))
+                {
+                    self.map.roles[index] = NameRole::Reference;
+                } else {
+                    self.bind(index, scope);
+                }
+            } else if self.depth_change(index) > 0
+                && index > start
+                && (self.is_identifier_at(index - 1)
+                    || self.is_op(index - 1, ")")
+                    || self.is_op(index - 1, "]"))
+            {
+                let close = self.matching_close(index, end);
+                self.expression(index + 1, close, scope);
+                index = close;
+                continue;
+            } else if token.kind == TokenKind::Name {
+                self.map.roles[index] = NameRole::Other;
+            }
+            index += 1;
+        }
+    }
+
+    fn expression(&mut self, start: usize, end: usize, scope: usize) {
+        let mut index = start;
+        while index < end {
+            self.map.scope_of[index] = scope;
+            if self.is_name(index, "lambda") {
+                index = self.lambda(index, end, scope);
+                continue;
+            }
+            if self.depth_change(index) > 0 {
+                let close = self.matching_close(index, end);
+                let is_comprehension = self
+                    .top_level(index + 1, close)
+                    .into_iter()
+                    .any(|inner| self.is_name(inner, "for"));
+                if is_comprehension {
+                    self.comprehension(index + 1, close, scope);
+                    index = close;
+                    continue;
+                }
+            }
+            if self.is_identifier_at(index) {
+                let after_open_or_comma =
+                    index > 0 && (self.is_op(index - 1, "(") || self.is_op(index - 1, ","));
+                let before_equals = self.is_op(index + 1, "=") && !self.is_op(index + 2, "=");
+                if index > 0 && self.is_op(index - 1, ".") {
+                    self.map.roles[index] = NameRole::Attribute;
+                } else if after_open_or_comma && before_equals {
+                    self.map.roles[index] = NameRole::Keyword;
+                } else if self.is_op(index + 1, ":") && self.is_op(index + 2, "=") {
+                    // `:=` binds in the nearest scope that isn't a comprehension
+                    let mut target_scope = scope;
+                    while self.map.scopes[target_scope].kind == ScopeKind::Comprehension {
+                        target_scope = self.map.scopes[target_scope].parent.unwrap_or(0);
+                    }
+                    self.bind(index, target_scope);
+                } else {
+                    self.map.roles[index] = NameRole::Reference;
+                }
+            }
+            index += 1;
+        }
+    }
+
+    // `lambda params: body`, where the body runs until a comma, `for` or
+    // closing bracket of the enclosing expression. Returns the index after it.
+    fn lambda(&mut self, keyword: usize, end: usize, scope: usize) -> usize {
+        let inner = self.new_scope(ScopeKind::Function, Some(scope));
+        let colon = self.find_colon(keyword + 1, end);
+        self.parameters(keyword + 1, colon, scope, inner);
+        if colon >= end {
+            return end;
+        }
+
+        let mut depth = 0;
+        let mut body_end = end;
+        for index in colon + 1..end {
+            depth += self.depth_change(index);
+            if depth < 0 || (depth == 0 && (self.is_op(index, ",") || self.is_name(index, "for"))) {
+                body_end = index;
+                break;
+            }
+        }
+        self.expression(colon + 1, body_end, inner);
+        body_end
+    }
+
+    // The inside of a comprehension's brackets, with its loop variables
+    fn comprehension(&mut self, start: usize, end: usize, outer: usize) {
+        let inner = self.new_scope(ScopeKind::Comprehension, Some(outer));
+        // The `for` and `if` clauses after the element expression
+        let mut clauses = Vec::new();
+        for index in self.top_level(start, end) {
+            if self.is_name(index, "for") || (!clauses.is_empty() && self.is_name(index, "if")) {
+                clauses.push(index);
+            }
+        }
+        let first_clause = clauses.first().copied().unwrap_or(end);
+        self.expression(start, first_clause, inner);
+
+        for (position, &clause) in clauses.iter().enumerate() {
+            let clause_end = clauses.get(position + 1).copied().unwrap_or(end);
+            if self.is_name(clause, "if") {
+                self.expression(clause, clause_end, inner);
+                continue;
+            }
+            self.map.scope_of[clause] = inner;
+            let in_at = self.find_name(clause, clause_end, "in");
+            self.target(clause + 1, in_at, inner);
+            // The first iterable is evaluated in the enclosing scope
+            let iterable_scope = if position == 0 { outer } else { inner };
+            self.expression(in_at, clause_end, iterable_scope);
+        }
+    }
+
+    // A `case` pattern: bare names capture the matched value, while dotted
+    // names, class names and keyword patterns refer to existing ones
+    fn pattern(&mut self, start: usize, end: usize, scope: usize) {
+        for index in start..end {
+            self.map.scope_of[index] = scope;
+            if !self.is_identifier_at(index) {
+                continue;
+            }
+            let after_open_or_comma =
+                index > 0 && (self.is_op(index - 1, "(") || self.is_op(index - 1, ","));
+            if index > 0 && self.is_op(index - 1, ".") {
+                self.map.roles[index] = NameRole::Attribute;
+            } else if self.is_op(index + 1, ".") || self.is_op(index + 1, "(") {
+                self.map.roles[index] = NameRole::Reference;
+            } else if after_open_or_comma && self.is_op(index + 1, "=") {
+                self.map.roles[index] = NameRole::Keyword;
+            } else if self.tokens[index].text != "_" {
+                self.bind(index, scope);
+            }
+        }
+    }
+}
+
+// Rename the variable, function or class at `line`/`column` (1-based) within
+// this file. Only names resolving to the same binding are changed, so a local
+// `value` in one function leaves another function's `value` alone, and names in
+// strings and keyword arguments are never touched. Attributes have no scope to
+// resolve and are refused.
+#[tauri::command]
+pub async fn rename_symbol(
+    path: String,
+    line: u32,
+    column: u32,
+    new_name: String,
+) -> Result<Vec<TextEdit>, String> {
+    let source = fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+    rename_edits(&source, line, column, &new_name)
+}
+
+fn rename_edits(
+    source: &str,
+    line: u32,
+    column: u32,
+    new_name: &str,
+) -> Result<Vec<TextEdit>, String> {
+    if !is_identifier(new_name) {
+        return Err(format!("'{}' is not a valid Python identifier", new_name));
+    }
+
+    let tokens = tokenize(source);
+
+    // Accept the cursor anywhere in the name or right after its last character
+    let target = tokens
+        .iter()
+        .position(|t| {
+            t.kind == TokenKind::Name
+                && t.line == line
+                && column >= t.column
+                && column <= t.column + t.text.chars().count() as u32
+        })
+        .ok_or("No symbol found at the given position")?;
+    let old_name = tokens[target].text;
+
+    if PYTHON_KEYWORDS.contains(&old_name) {
+        return Err(format!("Cannot rename the keyword '{}'", old_name));
+    }
+
+    let map = scope_map(&tokens);
+    match map.roles[target] {
+        NameRole::Attribute => {
+            return Err(format!(
+                "Cannot rename the attribute '{}': its owner is not known",
+                old_name
+            ))
+        }
+        NameRole::Keyword => {
+            return Err(format!(
+                "'{}' is a keyword argument here; rename the parameter instead",
+                old_name
+            ))
+        }
+        NameRole::Other => return Err(format!("Cannot rename '{}' here", old_name)),
+        _ => {}
+    }
+
+    let binding = map
+        .resolve(map.scope_of[target], old_name)
+        .ok_or_else(|| format!("Could not resolve the scope of '{}'", old_name))?;
+    if binding == 0 && !map.scopes[0].bound.contains(old_name) {
+        return Err(format!("'{}' is not defined in this file", old_name));
+    }
+
+    let mut edits = Vec::new();
+    for (index, t) in tokens.iter().enumerate() {
+        let renamable = matches!(
+            map.roles[index],
+            NameRole::Reference | NameRole::Binding | NameRole::Import
+        );
+        if t.kind != TokenKind::Name
+            || t.text != old_name
+            || !renamable
+            || map.resolve(map.scope_of[index], old_name) != Some(binding)
+        {
+            continue;
+        }
+
+        // Keep the imported name and bind it under the new one instead
+        let new_text = if map.roles[index] == NameRole::Import {
+            if tokens.get(index + 1).map_or(false, |next| next.text == ".") {
+                return Err(format!(
+                    "'{}' is bound by a dotted import and cannot be renamed",
+                    old_name
+                ));
+            }
+            format!("{} as {}", old_name, new_name)
+        } else {
+            new_name.to_string()
+        };
+        edits.push(TextEdit {
+            line: t.line,
+            start_col: t.column,
+            end_col: t.column + t.text.chars().count() as u32,
+            new_text,
+        });
+    }
+
+    Ok(edits)
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    let starts_ok = chars
+        .next()
+        .map_or(false, |c| c.is_alphabetic() || c == '_');
+    starts_ok && chars.all(|c| c.is_alphanumeric() || c == '_') && !PYTHON_KEYWORDS.contains(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rename_edits;
+
+    // Rename the name at `line`/`column` and return the edited source
+    fn rename(source: &str, line: u32, column: u32, new_name: &str) -> String {
+        let mut edits = rename_edits(source, line, column, new_name).unwrap();
+        edits.sort_by_key(|edit| std::cmp::Reverse((edit.line, edit.start_col)));
+        let mut lines: Vec<String> = source.lines().map(String::from).collect();
+        for edit in edits {
+            let text = &mut lines[edit.line as usize - 1];
+            let chars: Vec<char> = text.chars().collect();
+            let before: String = chars[..edit.start_col as usize - 1].iter().collect();
+            let after: String = chars[edit.end_col as usize - 1..].iter().collect();
+            *text = format!("{}{}{}", before, edit.new_text, after);
+        }
+        lines.join("\n") + "\n"
+    }
+
+    #[test]
+    fn global_declaration_refers_to_module_name() {
+        let source = "count = 0\n\
+                      def bump():\n    global count\n    count += 1\n\
+                      def local():\n    count = 5\n    return count\n";
+        assert_eq!(
+            rename(source, 1, 1, "total"),
+            "total = 0\n\
+             def bump():\n    global total\n    total += 1\n\
+             def local():\n    count = 5\n    return count\n"
+        );
+    }
+
+    #[test]
+    fn nonlocal_declaration_refers_to_enclosing_function() {
+        let source = "total = 1\n\
+                      def outer():\n    total = 0\n    def inner():\n        nonlocal total\n        total += 1\n    return total\n";
+        assert_eq!(
+            rename(source, 6, 9, "count"),
+            "total = 1\n\
+             def outer():\n    count = 0\n    def inner():\n        nonlocal count\n        count += 1\n    return count\n"
+        );
+    }
+
+    #[test]
+    fn nonlocal_without_enclosing_binding_is_an_error() {
+        let source =
+            "def outer():\n    def inner():\n        nonlocal missing\n        missing = 1\n";
+        assert!(rename_edits(source, 4, 9, "other").is_err());
+    }
+
+    #[test]
+    fn class_body_is_invisible_to_its_methods() {
+        let source = "size = 1\n\
+                      class Box:\n    size = 2\n    double = size * 2\n    def get(self):\n        return size, self.size\n";
+        assert_eq!(
+            rename(source, 3, 5, "width"),
+            "size = 1\n\
+             class Box:\n    width = 2\n    double = width * 2\n    def get(self):\n        return size, self.size\n"
+        );
+        assert_eq!(
+            rename(source, 1, 1, "area"),
+            "area = 1\n\
+             class Box:\n    size = 2\n    double = size * 2\n    def get(self):\n        return area, self.size\n"
+        );
+    }
+
+    #[test]
+    fn comprehension_variables_are_local_to_it() {
+        let source = "x = [1, 2]\nsquares = [x * x for x in x if x]\n";
+        assert_eq!(
+            rename(source, 2, 12, "item"),
+            "x = [1, 2]\nsquares = [item * item for item in x if item]\n"
+        );
+        assert_eq!(
+            rename(source, 1, 1, "values"),
+            "values = [1, 2]\nsquares = [x * x for x in values if x]\n"
+        );
+    }
+
+    #[test]
+    fn lambda_parameters_are_local_to_it() {
+        let source = "value = 3\nscale = lambda value, factor=value: value * factor\n";
+        assert_eq!(
+            rename(source, 2, 16, "v"),
+            "value = 3\nscale = lambda v, factor=value: v * factor\n"
+        );
+        assert_eq!(
+            rename(source, 1, 1, "default"),
+            "default = 3\nscale = lambda value, factor=default: value * factor\n"
+        );
+    }
+
+    #[test]
+    fn walrus_binds_outside_the_comprehension() {
+        let source = "def run(rows):\n    found = [last := row for row in rows]\n    return last\n";
+        assert_eq!(
+            rename(source, 3, 12, "final"),
+            "def run(rows):\n    found = [final := row for row in rows]\n    return final\n"
+        );
+    }
+
+    #[test]
+    fn plain_import_is_renamed_through_an_alias() {
+        let source = "import json\nfrom os import path\nprint(json.dumps(path.sep))\n";
+        assert_eq!(
+            rename(source, 1, 8, "js"),
+            "import json as js\nfrom os import path\nprint(js.dumps(path.sep))\n"
+        );
+        assert_eq!(
+            rename(source, 3, 18, "p"),
+            "import json\nfrom os import path as p\nprint(json.dumps(p.sep))\n"
+        );
+    }
+
+    #[test]
+    fn keyword_arguments_and_strings_are_left_alone() {
+        let source = "def f(name):\n    return g(name=name, label=\"name\", text=f\"{name}\")\n";
+        assert_eq!(
+            rename(source, 1, 7, "title"),
+            "def f(title):\n    return g(name=title, label=\"name\", text=f\"{name}\")\n"
+        );
+        assert!(rename_edits(source, 2, 14, "title").is_err());
+    }
+
+    #[test]
+    fn match_captures_bind_names() {
+        let source = "def show(point):\n    match point:\n        case Point(x=0, y=y) if y:\n            return y\n";
+        assert_eq!(
+            rename(source, 4, 20, "height"),
+            "def show(point):\n    match point:\n        case Point(x=0, y=height) if height:\n            return height\n"
+        );
+    }
+}
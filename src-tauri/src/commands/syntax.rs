@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::process::Stdio;
+
+use super::file::find_files_with_extension;
+use super::project::uv_project_command;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SyntaxErrorInfo {
+    pub line: u32,
+    pub column: Option<u32>,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SyntaxCheckResult {
+    pub ok: bool,
+    pub error: Option<SyntaxErrorInfo>,
+}
+
+#[tauri::command]
+pub async fn syntax_check_file(
+    project_path: String,
+    file_path: String,
+) -> Result<SyntaxCheckResult, String> {
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "python", "-m", "py_compile", &file_path])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = cmd.output()
+        .map_err(|e| format!("Failed to execute uv run python: {}", e))?;
+
+    if output.status.success() {
+        return Ok(SyntaxCheckResult {
+            ok: true,
+            error: None,
+        });
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let source_path = Path::new(&project_path).join(&file_path);
+    let source = fs::read_to_string(source_path).unwrap_or_default();
+
+    match parse_compile_error(&stderr, &source) {
+        Some(error) => Ok(SyntaxCheckResult {
+            ok: false,
+            error: Some(error),
+        }),
+        // Not a syntax error, e.g. the file or interpreter is missing
+        None => Err(stderr.to_string()),
+    }
+}
+
+// Files handed to one compileall run, keeping the command line short
+const COMPILE_BATCH_SIZE: usize = 200;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FileSyntaxError {
+    pub file: String,
+    pub line: u32,
+    pub column: Option<u32>,
+    pub message: String,
+}
+
+// Split quiet compileall output into (file, error output) per failing file:
+//
+// *** Error compiling 'main.py'...
+//   File "main.py", line 2
+// ...
+fn split_compileall_output(stdout: &str) -> Vec<(String, String)> {
+    let mut failures: Vec<(String, String)> = Vec::new();
+    for line in stdout.lines() {
+        if let Some(rest) = line.strip_prefix("*** Error compiling '") {
+            let file = rest.trim_end_matches("...").trim_end_matches('\'');
+            failures.push((file.to_string(), String::new()));
+        } else if let Some((_, output)) = failures.last_mut() {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    failures
+}
+
+// Compile every Python file in the project (virtualenvs and caches are
+// skipped) and report the ones that fail. Bytecode goes to a temporary cache
+// instead of __pycache__ directories next to the sources.
+#[tauri::command]
+pub async fn syntax_check_project(project_path: String) -> Result<Vec<FileSyntaxError>, String> {
+    let project_dir = Path::new(&project_path);
+    // Relative paths with forward slashes come back unescaped in the output
+    let files: Vec<String> = find_files_with_extension(project_dir, "py")
+        .iter()
+        .filter_map(|file| file.strip_prefix(project_dir).ok())
+        .map(|file| file.to_string_lossy().replace('\\', "/"))
+        .collect();
+    let pycache_prefix = std::env::temp_dir().join("pyra-compileall");
+
+    let mut errors = Vec::new();
+    for batch in files.chunks(COMPILE_BATCH_SIZE) {
+        let mut cmd = uv_project_command(&project_path);
+        cmd.args(["run", "python", "-m", "compileall", "-q"])
+            .args(batch)
+            .current_dir(&project_path)
+            .env("PYTHONPYCACHEPREFIX", &pycache_prefix)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute uv run python: {}", e))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let failures = split_compileall_output(&stdout);
+        // A failed run without any per-file errors means Python itself didn't start
+        if !output.status.success() && failures.is_empty() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        for (file, error_output) in failures {
+            let source = fs::read_to_string(project_dir.join(&file)).unwrap_or_default();
+            let file = project_dir.join(&file).to_string_lossy().to_string();
+            match parse_compile_error(&error_output, &source) {
+                Some(error) => errors.push(FileSyntaxError {
+                    file,
+                    line: error.line,
+                    column: error.column,
+                    message: error.message,
+                }),
+                // Not a syntax error, e.g. the file couldn't be read
+                None => errors.push(FileSyntaxError {
+                    file,
+                    line: 1,
+                    column: None,
+                    message: error_output
+                        .lines()
+                        .rev()
+                        .find(|line| !line.trim().is_empty())
+                        .unwrap_or("Failed to compile")
+                        .trim()
+                        .to_string(),
+                }),
+            }
+        }
+    }
+    Ok(errors)
+}
+
+// py_compile reports errors in one of two shapes:
+//
+//   File "main.py", line 2
+//     x = (1,
+//         ^
+// SyntaxError: '(' was never closed
+//
+// or, for indentation errors, a single line:
+//
+// Sorry: IndentationError: unexpected indent (main.py, line 2)
+fn parse_compile_error(stderr: &str, source: &str) -> Option<SyntaxErrorInfo> {
+    let lines: Vec<&str> = stderr.lines().collect();
+    let message_index = lines.iter().rposition(|line| {
+        let line = line.trim_start_matches("Sorry: ");
+        line.split_once(": ")
+            .map_or(false, |(kind, _)| kind.ends_with("Error") && !kind.contains(' '))
+    })?;
+    let mut message = lines[message_index].trim_start_matches("Sorry: ").trim().to_string();
+
+    // Line number from `File "...", line N`, or the "(file, line N)" suffix
+    let mut line = lines[..message_index].iter().rev().find_map(|l| {
+        let (_, rest) = l.trim_start().strip_prefix("File ")?.rsplit_once(", line ")?;
+        rest.split(',').next()?.trim().parse::<u32>().ok()
+    });
+    if line.is_none() {
+        if let Some(start) = message.rfind(", line ") {
+            if message.ends_with(')') {
+                line = message[start + 7..message.len() - 1].parse().ok();
+                if let Some(open) = message[..start].rfind(" (") {
+                    message.truncate(open);
+                }
+            }
+        }
+    }
+    let line = line?;
+
+    // The source line is shown dedented by traceback with four spaces of
+    // indent; the caret below it points at the offending column
+    let column = lines[..message_index]
+        .iter()
+        .rev()
+        .find(|l| l.trim_start().starts_with('^'))
+        .and_then(|caret_line| {
+            let caret = caret_line.find('^')?;
+            let indent = source
+                .lines()
+                .nth((line as usize).saturating_sub(1))
+                .map(|l| l.len() - l.trim_start().len())
+                .unwrap_or(0);
+            Some((caret.saturating_sub(4) + indent) as u32 + 1)
+        });
+
+    Some(SyntaxErrorInfo {
+        line,
+        column,
+        message,
+    })
+}
@@ -1,7 +1,18 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
-use std::process::Command;
+
+use super::git::git_command;
+use super::project::uv_project_command;
+use super::python::parse_requirements;
+use super::settings::uv_command;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GitProjectResult {
+    pub project_path: String,
+    pub dependencies: Vec<String>,
+    pub warnings: Vec<String>,
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ProjectTemplate {
@@ -625,7 +636,7 @@ pub async fn create_project_from_template(
         uv_args.extend_from_slice(&["--python", version]);
     }
 
-    let uv_init_result = Command::new("uv")
+    let uv_init_result = uv_command()
         .args(&uv_args)
         .current_dir(&project_dir)
         .output();
@@ -655,7 +666,7 @@ pub async fn create_project_from_template(
     // Install template dependencies if any
     if !template.dependencies.is_empty() {
         for dep in &template.dependencies {
-            let add_result = Command::new("uv")
+            let add_result = uv_project_command(&project_dir.to_string_lossy())
                 .args(&["add", dep])
                 .current_dir(&project_dir)
                 .output();
@@ -671,7 +682,7 @@ pub async fn create_project_from_template(
         }
 
         // Sync the project to install dependencies
-        let sync_result = Command::new("uv")
+        let sync_result = uv_project_command(&project_dir.to_string_lossy())
             .args(&["sync"])
             .current_dir(&project_dir)
             .output();
@@ -744,7 +755,7 @@ line-ending = "auto"
     )
 }
 
-fn create_gitignore() -> &'static str {
+pub(crate) fn create_gitignore() -> &'static str {
     r#"# Python
 __pycache__/
 *.py[cod]
@@ -791,6 +802,7 @@ coverage.xml
 # Virtual environments
 .env
 .venv
+.venvs/
 env/
 venv/
 ENV/
@@ -800,6 +812,7 @@ venv.bak/
 # IDEs
 .vscode/
 .idea/
+.pyra-session.json
 *.swp
 *.swo
 *~
@@ -818,4 +831,176 @@ outputs/
 logs/
 *.log
 "#
-}
\ No newline at end of file
+}
+
+// Only plain remote URLs are accepted. Anything else could make git run a
+// command (`ext::`) or be read as an option (`-u...`).
+fn validate_git_url(url: &str) -> Result<(), String> {
+    let invalid = || {
+        format!(
+            "Unsupported git URL '{}'. Use an https://, ssh:// or git:// URL, or user@host:path.",
+            url
+        )
+    };
+
+    if url.is_empty()
+        || url.starts_with('-')
+        || url.contains("::")
+        || url.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        return Err(invalid());
+    }
+
+    for scheme in ["https://", "ssh://", "git://"] {
+        if let Some(rest) = url.strip_prefix(scheme) {
+            return if rest.is_empty() { Err(invalid()) } else { Ok(()) };
+        }
+    }
+
+    // scp-like syntax, e.g. git@github.com:user/repo.git
+    match url.split_once(':') {
+        Some((user_host, path))
+            if user_host.contains('@') && !user_host.contains('/') && !path.is_empty() =>
+        {
+            Ok(())
+        }
+        _ => Err(invalid()),
+    }
+}
+
+// Git marks its object files read-only, which makes removal fail on Windows
+fn remove_git_dir(git_dir: &Path) -> std::io::Result<()> {
+    let result = fs::remove_dir_all(git_dir);
+    #[cfg(target_os = "windows")]
+    if result.is_err() {
+        clear_readonly(git_dir);
+        return fs::remove_dir_all(git_dir);
+    }
+    result
+}
+
+#[cfg(target_os = "windows")]
+fn clear_readonly(path: &Path) {
+    if let Ok(metadata) = fs::metadata(path) {
+        let mut permissions = metadata.permissions();
+        permissions.set_readonly(false);
+        let _ = fs::set_permissions(path, permissions);
+    }
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            clear_readonly(&entry.path());
+        }
+    }
+}
+
+// Dependencies declared in pyproject.toml, or requirements.txt without one
+fn detect_dependencies(project_dir: &Path, warnings: &mut Vec<String>) -> Vec<String> {
+    if let Ok(content) = fs::read_to_string(project_dir.join("pyproject.toml")) {
+        return match content.parse::<toml::Value>() {
+            Ok(pyproject) => pyproject
+                .get("project")
+                .and_then(|project| project.get("dependencies"))
+                .and_then(|deps| deps.as_array())
+                .map(|deps| {
+                    deps.iter()
+                        .filter_map(|dep| dep.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Err(e) => {
+                warnings.push(format!("Could not parse pyproject.toml: {}", e));
+                Vec::new()
+            }
+        };
+    }
+
+    match fs::read_to_string(project_dir.join("requirements.txt")) {
+        Ok(content) => parse_requirements(&content, warnings),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn run_uv(project_dir: &Path, args: &[&str]) -> Result<(), String> {
+    let output = uv_project_command(&project_dir.to_string_lossy())
+        .args(args)
+        .current_dir(project_dir)
+        .output()
+        .map_err(|e| format!("Failed to execute uv: {}", e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn create_project_from_git(
+    git_url: String,
+    destination: String,
+    init_venv: bool,
+) -> Result<GitProjectResult, String> {
+    let git_url = git_url.trim();
+    validate_git_url(git_url)?;
+
+    // git runs from the parent directory, so a relative destination has to be
+    // resolved first
+    let current_dir =
+        std::env::current_dir().map_err(|e| format!("Failed to get current directory: {}", e))?;
+    let project_dir = current_dir.join(&destination);
+    if project_dir.exists()
+        && fs::read_dir(&project_dir)
+            .map_err(|e| format!("Failed to read destination: {}", e))?
+            .next()
+            .is_some()
+    {
+        return Err(format!("Destination '{}' already exists and is not empty", destination));
+    }
+    let parent = project_dir
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(parent)
+        .map_err(|e| format!("Failed to create parent directory: {}", e))?;
+
+    // stdin is closed, so fail instead of waiting on a credential prompt
+    let output = git_command(&parent.to_string_lossy())
+        .args(["clone", "--depth", "1", "--", git_url])
+        .arg(&project_dir)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .map_err(|e| format!("Failed to execute git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git clone failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    // Start the new project with a fresh history
+    remove_git_dir(&project_dir.join(".git"))
+        .map_err(|e| format!("Failed to remove .git directory: {}", e))?;
+
+    let mut warnings = Vec::new();
+    let dependencies = detect_dependencies(&project_dir, &mut warnings);
+
+    if init_venv {
+        // A failed environment setup still leaves a usable project behind
+        let result = if project_dir.join("pyproject.toml").exists() {
+            run_uv(&project_dir, &["sync"])
+        } else if project_dir.join("requirements.txt").exists() {
+            run_uv(&project_dir, &["venv"])
+                .and_then(|_| run_uv(&project_dir, &["pip", "install", "-r", "requirements.txt"]))
+        } else {
+            run_uv(&project_dir, &["venv"])
+        };
+        if let Err(e) = result {
+            warnings.push(format!("Failed to set up the virtual environment: {}", e));
+        }
+    }
+
+    Ok(GitProjectResult {
+        project_path: project_dir.to_string_lossy().to_string(),
+        dependencies,
+        warnings,
+    })
+}
@@ -0,0 +1,421 @@
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Output, Stdio};
+use tauri::{Emitter, Window};
+
+use super::project::uv_project_command;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TestResult {
+    pub node_id: String,
+    pub outcome: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PytestReport {
+    pub collected: u32,
+    pub passed: u32,
+    pub failed: u32,
+    pub skipped: u32,
+    pub errors: u32,
+    pub tests: Vec<TestResult>,
+    pub output: String,
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DoctestFailure {
+    pub name: String,
+    pub line: u32,
+    pub example: String,
+    pub expected: String,
+    // Actual output, or the traceback when the example raised
+    pub got: String,
+    pub exception: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DoctestReport {
+    pub attempted: u32,
+    pub passed: u32,
+    pub failed: u32,
+    pub failures: Vec<DoctestFailure>,
+    pub output: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TestNode {
+    pub id: String,
+    pub name: String,
+    pub file: String,
+    pub children: Vec<TestNode>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TestDiscovery {
+    pub framework: String,
+    pub has_tests: bool,
+    pub tests: Vec<TestNode>,
+}
+
+#[tauri::command]
+pub async fn run_single_test(
+    window: Window,
+    project_path: String,
+    node_id: String,
+) -> Result<PytestReport, String> {
+    let mut cmd = uv_project_command(&project_path);
+    cmd.args(["run", "pytest", &node_id, "-v"])
+        .current_dir(&project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to execute uv run pytest: {}", e))?;
+
+    let stdout = child.stdout.take().unwrap();
+    let mut stderr = child.stderr.take().unwrap();
+
+    // Drain stderr on its own thread so it can't block stdout
+    let stderr_handle = std::thread::spawn(move || {
+        let mut buffer = String::new();
+        let _ = stderr.read_to_string(&mut buffer);
+        buffer
+    });
+
+    // Stream output lines as they arrive so long tests show progress
+    let mut output = String::new();
+    let reader = BufReader::new(stdout);
+    for line in reader.lines().map_while(Result::ok) {
+        let _ = window.emit("test-output", &format!("{}\n", line));
+        output.push_str(&line);
+        output.push('\n');
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("Failed to wait for pytest: {}", e))?;
+    let stderr_output = stderr_handle.join().unwrap_or_default();
+
+    if output.trim().is_empty() && !status.success() {
+        return Err(stderr_output);
+    }
+
+    let mut report = parse_pytest_output(&output);
+    if !stderr_output.trim().is_empty() {
+        report.output.push_str(&stderr_output);
+    }
+
+    // Exit code 4 is a usage error (e.g. "not found"), 5 means nothing was collected
+    if report.collected == 0 || matches!(status.code(), Some(4) | Some(5)) {
+        report.collected = 0;
+        report.message = Some(format!("No tests collected for '{}'", node_id));
+    }
+
+    Ok(report)
+}
+
+// Parse `pytest -v` output into per-test outcomes and summary counts
+fn parse_pytest_output(output: &str) -> PytestReport {
+    let mut report = PytestReport {
+        collected: 0,
+        passed: 0,
+        failed: 0,
+        skipped: 0,
+        errors: 0,
+        tests: Vec::new(),
+        output: output.to_string(),
+        message: None,
+    };
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+
+        // "collected 3 items" / "collected 1 item"
+        if let Some(rest) = trimmed.strip_prefix("collected ") {
+            if let Some(count) = rest.split_whitespace().next().and_then(|n| n.parse().ok()) {
+                report.collected = count;
+            }
+            continue;
+        }
+
+        // "tests/test_main.py::test_addition PASSED        [100%]"
+        if trimmed.contains("::") {
+            let mut parts = trimmed.split_whitespace();
+            if let (Some(node_id), Some(outcome)) = (parts.next(), parts.next()) {
+                let counter = match outcome {
+                    "PASSED" | "XPASS" => &mut report.passed,
+                    "FAILED" => &mut report.failed,
+                    "SKIPPED" | "XFAIL" => &mut report.skipped,
+                    "ERROR" => &mut report.errors,
+                    _ => continue,
+                };
+                *counter += 1;
+                report.tests.push(TestResult {
+                    node_id: node_id.to_string(),
+                    outcome: outcome.to_lowercase(),
+                });
+            }
+        }
+    }
+
+    report
+}
+
+// Lists unittest cases as {id, file, class, method}, for projects without pytest
+const UNITTEST_DISCOVER_SCRIPT: &str = r#"
+import inspect, json, os, unittest
+
+def walk(suite):
+    for test in suite:
+        if isinstance(test, unittest.TestSuite):
+            yield from walk(test)
+        else:
+            yield test
+
+tests = []
+for test in walk(unittest.defaultTestLoader.discover(".")):
+    cls = type(test)
+    if cls.__module__ == "unittest.loader":
+        continue  # placeholder for a module that failed to import
+    try:
+        path = os.path.relpath(inspect.getsourcefile(cls)).replace(os.sep, "/")
+    except (TypeError, ValueError):
+        path = cls.__module__
+    tests.append({"id": test.id(), "file": path, "class": cls.__name__,
+                  "method": test._testMethodName})
+print(json.dumps(tests))
+"#;
+
+fn uv_run(project_path: &str, args: &[&str]) -> Result<Output, String> {
+    let mut cmd = uv_project_command(project_path);
+    cmd.arg("run")
+        .args(args)
+        .current_dir(project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    cmd.output()
+        .map_err(|e| format!("Failed to execute uv run: {}", e))
+}
+
+#[tauri::command]
+pub async fn discover_tests(project_path: String) -> Result<TestDiscovery, String> {
+    let output = uv_run(&project_path, &["pytest", "--collect-only", "-q"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // uv reports a missing executable as "Failed to spawn: `pytest`"
+    let pytest_missing = stderr.contains("Failed to spawn") && stderr.contains("pytest");
+    if pytest_missing {
+        return discover_unittest(&project_path);
+    }
+
+    // Exit code 5 means nothing was collected
+    if !output.status.success() && output.status.code() != Some(5) {
+        let message = if stdout.trim().is_empty() { stderr } else { stdout };
+        return Err(format!("Test collection failed:\n{}", message.trim()));
+    }
+
+    let mut tests = Vec::new();
+    for node_id in stdout.lines().map(str::trim).filter(|l| l.contains("::")) {
+        // Parameter ids may themselves contain "::", so only split before them
+        let (path, params) = match node_id.find('[') {
+            Some(bracket) => node_id.split_at(bracket),
+            None => (node_id, ""),
+        };
+        let mut parts = path.split("::");
+        let file = parts.next().unwrap_or_default();
+        let mut segments = vec![(file.to_string(), file.to_string())];
+        let mut id = file.to_string();
+        for part in parts {
+            id = format!("{}::{}", id, part);
+            segments.push((id.clone(), part.to_string()));
+        }
+        if let Some(last) = segments.last_mut() {
+            last.0.push_str(params);
+            last.1.push_str(params);
+        }
+        insert_test(&mut tests, file, &segments);
+    }
+
+    Ok(TestDiscovery {
+        framework: "pytest".to_string(),
+        has_tests: !tests.is_empty(),
+        tests,
+    })
+}
+
+fn discover_unittest(project_path: &str) -> Result<TestDiscovery, String> {
+    let output = uv_run(project_path, &["python", "-c", UNITTEST_DISCOVER_SCRIPT])?;
+    if !output.status.success() {
+        return Err(format!(
+            "Test discovery failed:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let cases: Vec<serde_json::Value> = serde_json::from_str(stdout.lines().last().unwrap_or("[]"))
+        .map_err(|e| format!("Failed to parse unittest discovery: {}", e))?;
+
+    let mut tests = Vec::new();
+    for case in &cases {
+        let field = |name: &str| case[name].as_str().unwrap_or_default().to_string();
+        let (id, file, class, method) = (field("id"), field("file"), field("class"), field("method"));
+        // "package.module.Class.method" -> "package.module.Class"
+        let class_id = id
+            .strip_suffix(&method)
+            .map(|prefix| prefix.trim_end_matches('.').to_string())
+            .unwrap_or_else(|| class.clone());
+        let segments = [(file.clone(), file.clone()), (class_id, class), (id, method)];
+        insert_test(&mut tests, &file, &segments);
+    }
+
+    Ok(TestDiscovery {
+        framework: "unittest".to_string(),
+        has_tests: !tests.is_empty(),
+        tests,
+    })
+}
+
+// Add a test to the tree along its (id, name) path, reusing existing nodes so
+// tests keep the order they were collected in
+fn insert_test(nodes: &mut Vec<TestNode>, file: &str, segments: &[(String, String)]) {
+    let ((id, name), rest) = match segments.split_first() {
+        Some(first) => first,
+        None => return,
+    };
+    let index = match nodes.iter().position(|node| &node.id == id) {
+        Some(index) => index,
+        None => {
+            nodes.push(TestNode {
+                id: id.clone(),
+                name: name.clone(),
+                file: file.to_string(),
+                children: Vec::new(),
+            });
+            nodes.len() - 1
+        }
+    };
+    insert_test(&mut nodes[index].children, file, rest);
+}
+
+#[tauri::command]
+pub async fn run_doctests(project_path: String, file_path: String) -> Result<DoctestReport, String> {
+    let output = uv_run(&project_path, &["python", "-m", "doctest", "-v", &file_path])?;
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+    // "4 tests in 3 items." and "1 passed and 3 failed."
+    let mut attempted = None;
+    let mut passed = 0;
+    let mut failed = 0;
+    for line in stdout.lines() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [count, "tests" | "test", "in", ..] => attempted = count.parse().ok(),
+            [p, "passed", "and", f, "failed."] => {
+                passed = p.parse().unwrap_or(0);
+                failed = f.parse().unwrap_or(0);
+            }
+            _ => {}
+        }
+    }
+
+    // Without a summary the module failed to import or its docstrings
+    // couldn't be parsed
+    let attempted = match attempted {
+        Some(attempted) => attempted,
+        None => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(if stderr.trim().is_empty() {
+                stdout
+            } else {
+                stderr.to_string()
+            });
+        }
+    };
+
+    Ok(DoctestReport {
+        attempted,
+        passed,
+        failed,
+        failures: parse_doctest_failures(&stdout),
+        output: stdout,
+    })
+}
+
+// Failure reports in doctest output look like:
+//
+//   File "example.py", line 5, in example.add
+//   Failed example:
+//       add(1, 1)
+//   Expected:
+//       3
+//   Got:
+//       2
+//
+// with "Exception raised:" in place of "Got:" when the example raised
+fn parse_doctest_failures(output: &str) -> Vec<DoctestFailure> {
+    let mut failures = Vec::new();
+    let mut current: Option<DoctestFailure> = None;
+    let mut section = "";
+
+    for line in output.lines() {
+        if let Some(failure) = current.as_mut() {
+            if let Some(text) = line.strip_prefix("    ") {
+                let target = match section {
+                    "example" => &mut failure.example,
+                    "expected" => &mut failure.expected,
+                    "got" => &mut failure.got,
+                    _ => continue,
+                };
+                target.push_str(text);
+                target.push('\n');
+                continue;
+            }
+            match line {
+                "Failed example:" => section = "example",
+                "Expected:" => section = "expected",
+                "Got:" => section = "got",
+                "Exception raised:" => {
+                    section = "got";
+                    failure.exception = true;
+                }
+                "Expected nothing" | "Got nothing" => section = "",
+                _ => {
+                    failures.extend(current.take());
+                    section = "";
+                }
+            }
+            if current.is_some() {
+                continue;
+            }
+        }
+
+        // File "path", line 5, in module.function
+        if let Some(rest) = line.strip_prefix("File \"") {
+            let location = rest.rsplit_once("\", line ").map(|(_, location)| location);
+            if let Some((line_number, name)) = location.and_then(|l| l.split_once(", in ")) {
+                current = Some(DoctestFailure {
+                    name: name.to_string(),
+                    line: line_number.parse().unwrap_or(0),
+                    example: String::new(),
+                    expected: String::new(),
+                    got: String::new(),
+                    exception: false,
+                });
+            }
+        }
+    }
+    failures.extend(current);
+
+    for failure in &mut failures {
+        for text in [&mut failure.example, &mut failure.expected, &mut failure.got] {
+            let trimmed = text.trim_end().to_string();
+            *text = trimmed;
+        }
+    }
+    failures
+}
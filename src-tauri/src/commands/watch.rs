@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::sync::Mutex;
+
+use super::file::find_files_with_extension;
+
+// Active watchers keyed by watch id; clearing the flag stops the watcher task
+pub type WatchManager = Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>;
+
+pub fn create_watch_manager() -> WatchManager {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+// How often watchers poll the file system for modifications
+pub const POLL_INTERVAL_MS: u64 = 300;
+
+pub type Snapshot = HashMap<PathBuf, SystemTime>;
+
+// Record modification times of all files under `root` with the given extension.
+// A plain file as `root` is always included.
+pub fn snapshot_files(root: &Path, extension: &str) -> Snapshot {
+    let paths = if root.is_file() {
+        vec![root.to_path_buf()]
+    } else {
+        find_files_with_extension(root, extension)
+    };
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+// Files that were added or modified between two snapshots
+pub fn changed_files(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(*modified))
+        .map(|(path, _)| path.clone())
+        .collect()
+}
+
+// Register a watcher under `watch_id`, stopping any previous watcher with that id
+pub async fn register_watch(manager: &WatchManager, watch_id: &str) -> Arc<AtomicBool> {
+    let running = Arc::new(AtomicBool::new(true));
+    let mut watches = manager.lock().await;
+    if let Some(previous) = watches.insert(watch_id.to_string(), Arc::clone(&running)) {
+        previous.store(false, Ordering::SeqCst);
+    }
+    running
+}
+
+// Stop the watcher registered under `watch_id`, returning whether one existed
+pub async fn stop_watch(manager: &WatchManager, watch_id: &str) -> bool {
+    let mut watches = manager.lock().await;
+    match watches.remove(watch_id) {
+        Some(running) => {
+            running.store(false, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
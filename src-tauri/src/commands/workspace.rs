@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::file::write_file_atomic;
+
+const WORKSPACE_FILE: &str = ".pyra-workspace.json";
+
+// Related projects opened together. Commands already take an explicit
+// `project_path`, so switching projects is up to the frontend.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Workspace {
+    pub name: String,
+    pub projects: Vec<String>,
+    pub active_project: Option<String>,
+}
+
+// Accept either the workspace file itself or the directory containing it
+fn workspace_file(path: &str) -> PathBuf {
+    let path = Path::new(path);
+    if path.is_dir() {
+        path.join(WORKSPACE_FILE)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+fn read_workspace(file: &Path) -> Result<Workspace, String> {
+    let content =
+        fs::read_to_string(file).map_err(|e| format!("Failed to read workspace: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse workspace: {}", e))
+}
+
+fn write_workspace(file: &Path, workspace: &Workspace) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(workspace)
+        .map_err(|e| format!("Failed to serialize workspace: {}", e))?;
+    write_file_atomic(file, content.as_bytes())
+        .map_err(|e| format!("Failed to save workspace: {}", e))
+}
+
+// Drop duplicate projects and an active project that isn't in the list
+fn normalize_workspace(workspace: &mut Workspace) {
+    let mut seen = Vec::new();
+    workspace.projects.retain(|project| {
+        let duplicate = seen.contains(project);
+        seen.push(project.clone());
+        !duplicate
+    });
+    let active_listed = workspace
+        .active_project
+        .as_ref()
+        .map_or(false, |active| workspace.projects.contains(active));
+    if !active_listed {
+        workspace.active_project = workspace.projects.first().cloned();
+    }
+}
+
+#[tauri::command]
+pub async fn save_workspace(path: String, mut workspace: Workspace) -> Result<Workspace, String> {
+    normalize_workspace(&mut workspace);
+    write_workspace(&workspace_file(&path), &workspace)?;
+    Ok(workspace)
+}
+
+// Projects that no longer exist on disk are left out
+#[tauri::command]
+pub async fn load_workspace(path: String) -> Result<Workspace, String> {
+    let mut workspace = read_workspace(&workspace_file(&path))?;
+    workspace
+        .projects
+        .retain(|project| Path::new(project).is_dir());
+    normalize_workspace(&mut workspace);
+    Ok(workspace)
+}
+
+#[tauri::command]
+pub async fn add_project_to_workspace(
+    path: String,
+    project_path: String,
+) -> Result<Workspace, String> {
+    if !Path::new(&project_path).is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+
+    let file = workspace_file(&path);
+    let mut workspace = read_workspace(&file)?;
+    if !workspace.projects.contains(&project_path) {
+        workspace.projects.push(project_path);
+    }
+    normalize_workspace(&mut workspace);
+    write_workspace(&file, &workspace)?;
+    Ok(workspace)
+}
+
+#[tauri::command]
+pub async fn remove_project_from_workspace(
+    path: String,
+    project_path: String,
+) -> Result<Workspace, String> {
+    let file = workspace_file(&path);
+    let mut workspace = read_workspace(&file)?;
+    workspace
+        .projects
+        .retain(|project| project != &project_path);
+    normalize_workspace(&mut workspace);
+    write_workspace(&file, &workspace)?;
+    Ok(workspace)
+}
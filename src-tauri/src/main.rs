@@ -15,6 +15,9 @@ fn greet(name: &str) -> String {
 fn main() {
     let process_manager = commands::python::create_process_manager();
     let debug_manager = commands::debug::create_debug_manager();
+    let watch_manager = commands::watch::create_watch_manager();
+    let jedi_cache = commands::jedi::create_jedi_cache();
+    let pty_manager = commands::pty::create_pty_manager();
 
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
@@ -23,6 +26,9 @@ fn main() {
         .plugin(tauri_plugin_process::init())
         .manage(process_manager)
         .manage(debug_manager)
+        .manage(watch_manager)
+        .manage(jedi_cache)
+        .manage(pty_manager)
         .invoke_handler(tauri::generate_handler![
             greet,
             commands::file::read_file,
@@ -32,53 +38,171 @@ fn main() {
             commands::file::create_directory,
             commands::file::delete_file,
             commands::file::file_exists,
+            commands::file::get_available_disk_space,
+            commands::file::peek_file,
             commands::file::open_file_dialog,
             commands::file::save_file_dialog,
+            commands::file::format_config_file,
+            commands::settings::get_app_settings,
+            commands::settings::save_app_settings,
+            commands::settings::diagnose_uv,
+            commands::settings::add_recent_file,
+            commands::settings::get_recent_files,
             commands::python::check_uv_installed,
             commands::python::ensure_uv_installed,
+            commands::python::check_uv_version,
+            commands::python::update_uv,
+            commands::python::list_uv_tools,
+            commands::python::install_uv_tool,
+            commands::python::uninstall_uv_tool,
             commands::python::list_python_versions,
+            commands::python::get_available_python_versions,
+            commands::python::discover_system_pythons,
             commands::python::install_python_version,
+            commands::python::install_required_python,
             commands::python::create_venv,
+            commands::python::create_named_venv,
+            commands::python::list_venvs,
             commands::python::check_venv_exists,
             commands::python::install_package,
+            commands::python::install_packages,
             commands::python::uninstall_package,
             commands::python::list_packages,
+            commands::python::get_dependency_licenses,
             commands::python::get_dependency_tree,
+            commands::python::get_dependency_graph_dot,
+            commands::python::import_requirements_to_pyproject,
+            commands::python::diff_against_requirements,
             commands::python::run_script,
             commands::python::run_script_with_output_streaming,
+            commands::python::run_script_with_traceback,
             commands::python::run_script_simple,
             commands::python::stop_running_script,
+            commands::python::get_process_stats,
+            commands::python::get_running_process_id,
+            commands::python::profile_script,
             commands::python::init_uv_project,
             commands::python::sync_uv_project,
+            commands::python::sync_dry_run,
             commands::python::run_script_with_uv,
+            commands::python::eval_snippet,
+            commands::python::benchmark_snippet,
+            commands::python::profile_imports,
+            commands::python::get_python_environment_info,
+            commands::pty::run_script_pty,
+            commands::pty::send_pty_input,
+            commands::pty::resize_pty,
+            commands::python::run_script_capturing_figures,
+            commands::python::run_script_with_memory,
             commands::python::run_script_with_uv_streaming,
+            commands::python::run_project_script,
+            commands::python::run_command_streaming,
+            commands::python::run_script_watch,
+            commands::python::stop_script_watch,
+            commands::python::start_autosync,
+            commands::python::stop_autosync,
             commands::project::create_new_project,
             commands::project::open_project_dialog,
             commands::project::load_project_config,
             commands::project::save_project_config,
+            commands::project::set_active_venv,
+            commands::project::save_session,
+            commands::project::load_session,
+            commands::workspace::save_workspace,
+            commands::workspace::load_workspace,
+            commands::workspace::add_project_to_workspace,
+            commands::workspace::remove_project_from_workspace,
+            commands::notebook::notebook_to_script,
+            commands::notebook::script_to_notebook,
+            commands::scratch::create_scratch_file,
+            commands::scratch::list_scratch_files,
+            commands::scratch::delete_scratch_file,
             commands::project::get_recent_projects,
             commands::project::read_pyproject_toml,
             commands::project::write_pyproject_toml,
             commands::project::check_pyproject_exists,
+            commands::project::get_lockfile_hash,
+            commands::project::rename_project,
+            commands::project::detect_run_config,
+            commands::project::save_run_config,
+            commands::project::list_run_configs,
+            commands::project::delete_run_config,
+            commands::project::generate_readme,
             commands::templates::get_project_templates,
             commands::templates::create_project_from_template,
+            commands::templates::create_project_from_git,
             commands::ruff::check_ruff_installed,
             commands::ruff::install_ruff_with_uv,
             commands::ruff::ruff_check_file,
             commands::ruff::ruff_check_project,
+            commands::ruff::ruff_check_changed,
+            commands::ruff::ruff_check_project_streaming,
+            commands::ruff::check_python_compatibility,
             commands::ruff::ruff_format_file,
             commands::ruff::ruff_format_project,
+            commands::ruff::ruff_format_project_check,
             commands::ruff::ruff_fix_file,
+            commands::ruff::fix_and_format_file,
+            commands::ruff::cleanup_imports,
+            commands::ruff::get_available_fixes,
+            commands::ruff::apply_fix,
+            commands::ruff::add_noqa,
+            commands::ruff::start_ruff_watch,
+            commands::ruff::stop_ruff_watch,
             commands::ruff::create_ruff_config,
+            commands::mypy::mypy_check_project,
+            commands::isort::isort_file,
+            commands::isort::isort_check,
+            commands::search::scan_todos,
+            commands::search::find_importers,
+            commands::symbols::get_document_symbols,
+            commands::symbols::rename_symbol,
+            commands::editor::toggle_line_comment,
+            commands::editor::detect_indentation,
+            commands::editor::normalize_file,
+            commands::syntax::syntax_check_file,
+            commands::syntax::syntax_check_project,
+            commands::jedi::get_completions,
+            commands::jedi::get_hover_info,
+            commands::jedi::goto_definition,
+            commands::jedi::reset_environment_cache,
+            commands::git::read_gitignore,
+            commands::git::add_gitignore_entry,
+            commands::git::get_ignored_files,
+            commands::git::git_branch_info,
+            commands::git::git_stage_file,
+            commands::git::git_unstage_file,
+            commands::git::git_discard_changes,
+            commands::git::git_file_diff_content,
+            commands::git::git_blame,
+            commands::git::git_file_history,
+            commands::git::git_restore_file_version,
+            commands::git::git_checkout_file_version,
+            commands::git::setup_git_hooks,
+            commands::testing::run_single_test,
+            commands::testing::discover_tests,
+            commands::testing::run_doctests,
             commands::debug::start_debug_session,
+            commands::debug::attach_debug_session,
             commands::debug::debug_continue,
             commands::debug::debug_step_over,
             commands::debug::debug_step_into,
             commands::debug::debug_step_out,
+            commands::debug::get_threads,
             commands::debug::get_stack_trace,
+            commands::debug::get_source,
             commands::debug::get_scopes,
             commands::debug::get_variables,
+            commands::debug::get_variable_repr,
+            commands::debug::inspect_variable,
+            commands::debug::get_debug_capabilities,
+            commands::debug::evaluate_watches,
+            commands::debug::set_data_breakpoint,
+            commands::debug::export_debug_state,
             commands::debug::stop_debug_session,
+            commands::debug::validate_breakpoint_lines,
+            commands::debug::is_port_available,
+            commands::debug::find_free_port,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
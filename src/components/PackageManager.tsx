@@ -58,7 +58,7 @@ export function PackageManager({ projectPath, onConsoleOutput, onConsoleError }:
     try {
       const result = await TauriAPI.installPackage(projectPath, packageName)
       onConsoleOutput?.(t('messages.packageInstalled', { name: packageName }))
-      onConsoleOutput?.(`${result}`)
+      onConsoleOutput?.(`${result.output}`)
 
       // Reload packages list
       await loadPackages()
@@ -79,7 +79,7 @@ export function PackageManager({ projectPath, onConsoleOutput, onConsoleError }:
     try {
       const result = await TauriAPI.uninstallPackage(projectPath, packageName)
       onConsoleOutput?.(t('messages.packageUninstalled', { name: packageName }))
-      onConsoleOutput?.(`${result}`)
+      onConsoleOutput?.(`${result.output}`)
 
       // Reload packages list
       await loadPackages()
@@ -67,7 +67,7 @@ export function ProjectPanel({ projectPath, onConsoleOutput, onConsoleError }: P
       onConsoleOutput?.(t('messages.syncingDependencies'))
       const syncResult = await TauriAPI.syncUvProject(projectPath)
       onConsoleOutput?.(t('messages.packageInstalled', { name: 'dependencies' }))
-      onConsoleOutput?.(`${syncResult}`)
+      onConsoleOutput?.(`${syncResult.output}`)
 
       setVenvExists(true)
     } catch (error) {
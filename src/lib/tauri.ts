@@ -7,11 +7,83 @@ export interface FileItem {
   size?: number;
 }
 
+export interface FilePeek {
+  is_binary: boolean;
+  preview: string;
+  detected_type: string;
+}
+
+export interface ConfigParseError {
+  message: string;
+  line?: number;
+  column?: number;
+}
+
+export interface ConfigFormatResult {
+  format: 'json' | 'toml' | 'yaml';
+  content?: string;
+  error?: ConfigParseError;
+  warning?: string;
+}
+
+export interface AppSettings {
+  theme: string;
+  default_python_version?: string;
+  default_template?: string;
+  uv_path?: string;
+  terminal_shell?: string;
+}
+
+export interface UvDiagnosis {
+  on_path: boolean;
+  found_path?: string;
+  searched: string[];
+  persisted: boolean;
+}
+
+export interface GitProjectResult {
+  project_path: string;
+  dependencies: string[];
+  warnings: string[];
+}
+
 export interface Package {
   name: string;
   version: string;
 }
 
+export interface PythonVersion {
+  key: string;
+  implementation: string;
+  version: string;
+  installed: boolean;
+  path?: string;
+}
+
+export interface DiscoveredPython {
+  path: string;
+  version: string;
+  source: 'path' | 'pyenv' | 'homebrew' | 'system' | 'py-launcher';
+}
+
+export interface PackageLicense {
+  name: string;
+  version: string;
+  license: string;
+  flagged: boolean;
+}
+
+export interface UvVersionInfo {
+  version: string;
+  minimum_version: string;
+  meets_minimum: boolean;
+}
+
+export interface UvTool {
+  name: string;
+  version: string;
+}
+
 export interface PackageWithDeps {
   name: string;
   version: string;
@@ -19,11 +91,213 @@ export interface PackageWithDeps {
   depth: number;
 }
 
+export interface PackageChange {
+  name: string;
+  old_version?: string;
+  new_version?: string;
+}
+
+export interface LockDiff {
+  added: PackageChange[];
+  removed: PackageChange[];
+  updated: PackageChange[];
+}
+
+export interface UvOperationResult {
+  output: string;
+  lock_diff: LockDiff;
+}
+
+export interface AutosyncEvent {
+  watch_id: string;
+  success: boolean;
+  output: string;
+  lock_diff?: LockDiff;
+}
+
+export interface BulkInstallResult {
+  installed: string[];
+  failed: string[];
+  output: string;
+  batch_failed: boolean;
+  lock_diff: LockDiff;
+}
+
 export interface DependencyTree {
   packages: PackageWithDeps[];
   total_count: number;
 }
 
+export interface CursorPos {
+  line: number;
+  column: number;
+}
+
+export interface IndentationInfo {
+  uses_tabs: boolean;
+  indent_size: number;
+  trailing_whitespace_lines: number[];
+}
+
+export interface ScratchFile {
+  path: string;
+  name: string;
+  size: number;
+  modified: number;
+}
+
+export interface Workspace {
+  name: string;
+  projects: string[];
+  active_project?: string;
+}
+
+export interface EditorSession {
+  open_files: string[];
+  active_file?: string;
+  cursor_positions: Record<string, CursorPos>;
+}
+
+export interface VenvInfo {
+  name: string;
+  path: string;
+  python_version?: string;
+  active: boolean;
+}
+
+export interface MemorySample {
+  elapsed_ms: number;
+  rss_bytes: number;
+}
+
+export interface MemoryRunResult {
+  peak_memory_bytes: number;
+  exit_code?: number;
+  duration_ms: number;
+}
+
+export interface FigureRunResult {
+  output: string;
+  success: boolean;
+  figures: string[];
+}
+
+export interface TracebackFrame {
+  file: string;
+  line: number;
+  function: string;
+  code?: string;
+}
+
+export interface ExceptionTrace {
+  exception_type: string;
+  message: string;
+  frames: TracebackFrame[];
+}
+
+export interface TracebackRunResult {
+  stdout: string;
+  stderr: string;
+  exit_code?: number;
+  exception?: ExceptionTrace;
+  chained: ExceptionTrace[];
+}
+
+export interface ScriptLogEntry {
+  level: 'DEBUG' | 'INFO' | 'WARNING' | 'ERROR' | 'CRITICAL';
+  logger?: string;
+  message: string;
+  stream: 'stdout' | 'stderr';
+}
+
+export interface RawOutputChunk {
+  stream: 'stdout' | 'stderr';
+  data: string;
+}
+
+export interface PtyOutput {
+  id: string;
+  data: string;
+}
+
+export interface PtyExit {
+  id: string;
+  exit_code?: number;
+}
+
+export interface CommandStarted {
+  id: string;
+  program: string;
+  pid: number;
+}
+
+export interface PythonEnvironmentInfo {
+  executable: string;
+  version: string;
+  sys_path: string[];
+  site_packages: string[];
+  prefix: string;
+  venv_exists: boolean;
+}
+
+export interface SnippetResult {
+  stdout: string;
+  stderr: string;
+  exit_code?: number;
+  timed_out: boolean;
+}
+
+export interface BenchmarkResult {
+  loops: number;
+  time_per_loop_ns: number;
+}
+
+export interface ImportTiming {
+  module: string;
+  self_us: number;
+  cumulative_us: number;
+  children: ImportTiming[];
+}
+
+export interface ProfileEntry {
+  function: string;
+  file: string;
+  line: number;
+  calls: number;
+  total_time: number;
+  cumulative_time: number;
+}
+
+export interface FailedRequirement {
+  requirement: string;
+  error: string;
+}
+
+export interface RequirementsImportResult {
+  added: string[];
+  failed: FailedRequirement[];
+  warnings: string[];
+}
+
+export interface ProcessStats {
+  cpu_percent: number;
+  memory_bytes: number;
+  num_threads?: number;
+}
+
+export interface SyncAction {
+  action: 'install' | 'remove' | 'update';
+  package: string;
+  version: string;
+}
+
+export interface VersionMismatch {
+  package: string;
+  required?: string;
+  installed?: string;
+  status: 'Match' | 'Mismatch' | 'Missing' | 'Extra';
+}
+
 export interface PyProjectToml {
   project: ProjectMetadata;
   dependencies: string[];
@@ -46,6 +320,37 @@ export interface BuildSystem {
   build_backend: string;
 }
 
+export interface GeneratedReadme {
+  content: string;
+  path: string;
+  existed: boolean;
+  written: boolean;
+}
+
+export interface RunSuggestion {
+  label: string;
+  command: string;
+  args: string[];
+}
+
+export interface RunConfig {
+  name: string;
+  command: string;
+  args: string[];
+  env: Record<string, string>;
+  working_dir?: string;
+}
+
+export interface RenameProjectResult {
+  project_path: string;
+  warnings: string[];
+}
+
+export interface LockfileHash {
+  lock_hash: string;
+  dependencies_hash: string;
+}
+
 export interface RuffDiagnostic {
   rule: string;
   message: string;
@@ -63,6 +368,140 @@ export interface RuffCheckResult {
   errors: string[];
 }
 
+export interface DiagnosticsBatch {
+  files: string[];
+  diagnostics: RuffDiagnostic[];
+}
+
+export interface DiagnosticsComplete {
+  files_checked: number;
+  diagnostic_count: number;
+  errors: string[];
+}
+
+export interface FixAndFormatResult {
+  fixed: number;
+  formatted: boolean;
+  remaining_diagnostics: RuffDiagnostic[];
+  content: string;
+  errors: string[];
+}
+
+export interface ImportCleanupResult {
+  removed: number;
+  reordered: number;
+  content: string;
+}
+
+export interface CompatibilityResult {
+  target_version: string;
+  diagnostics: RuffDiagnostic[];
+  errors: string[];
+}
+
+export interface RuffFix {
+  rule: string;
+  message: string;
+  line: number;
+  column: number;
+  end_line: number;
+  end_column: number;
+  fix_message?: string;
+  applicability: 'safe' | 'unsafe' | 'display-only';
+}
+
+export interface RuffWatchEvent {
+  watch_id: string;
+  file_path: string;
+  result: RuffCheckResult;
+}
+
+export interface MypyDiagnostic {
+  line: number;
+  column: number;
+  severity: string;
+  message: string;
+  code?: string;
+}
+
+export interface MypyProjectResult {
+  diagnostics: Record<string, MypyDiagnostic[]>;
+  summary?: string;
+}
+
+export interface IsortCheckResult {
+  sorted: boolean;
+  diff: string;
+}
+
+export interface ImportLocation {
+  file: string;
+  line: number;
+}
+
+export interface TodoItem {
+  file: string;
+  line: number;
+  tag: string;
+  text: string;
+}
+
+export interface DocumentSymbol {
+  name: string;
+  kind: 'class' | 'function' | 'method';
+  line: number;
+  column: number;
+  children: DocumentSymbol[];
+}
+
+export interface SyntaxErrorInfo {
+  line: number;
+  column?: number;
+  message: string;
+}
+
+export interface SyntaxCheckResult {
+  ok: boolean;
+  error?: SyntaxErrorInfo;
+}
+
+export interface FileSyntaxError {
+  file: string;
+  line: number;
+  column?: number;
+  message: string;
+}
+
+export interface Completion {
+  label: string;
+  kind: string;
+  detail: string;
+}
+
+export interface HoverInfo {
+  signature: string;
+  docstring: string;
+  type: string;
+}
+
+export interface Location {
+  file: string;
+  line: number;
+  column: number;
+}
+
+export interface DefinitionResult {
+  location?: Location;
+  reason?: string;
+}
+
+export interface TextEdit {
+  line: number;
+  start_col: number;
+  end_col: number;
+  new_text: string;
+}
+
 export interface ProjectTemplate {
   id: string;
   name: string;
@@ -78,6 +517,88 @@ export interface TemplateFile {
   is_directory: boolean;
 }
 
+export interface TestResult {
+  node_id: string;
+  outcome: string;
+}
+
+export interface PytestReport {
+  collected: number;
+  passed: number;
+  failed: number;
+  skipped: number;
+  errors: number;
+  tests: TestResult[];
+  output: string;
+  message?: string;
+}
+
+export interface TestNode {
+  id: string;
+  name: string;
+  file: string;
+  children: TestNode[];
+}
+
+export interface DoctestFailure {
+  name: string;
+  line: number;
+  example: string;
+  expected: string;
+  got: string;
+  exception: boolean;
+}
+
+export interface DoctestReport {
+  attempted: number;
+  passed: number;
+  failed: number;
+  failures: DoctestFailure[];
+  output: string;
+}
+
+export interface TestDiscovery {
+  framework: string;
+  has_tests: boolean;
+  tests: TestNode[];
+}
+
+export interface GitBranchInfo {
+  branch: string;
+  detached: boolean;
+  ahead: number;
+  behind: number;
+  has_upstream: boolean;
+}
+
+export interface FileDiffContent {
+  old_content: string;
+  new_content: string;
+  is_new: boolean;
+  is_deleted: boolean;
+}
+
+export interface BlameLine {
+  line: number;
+  commit: string;
+  author: string;
+  timestamp: number;
+  summary: string;
+  uncommitted: boolean;
+}
+
+export interface GitCommit {
+  hash: string;
+  author: string;
+  date: string;
+  message: string;
+}
+
+export interface FileBlame {
+  tracked: boolean;
+  lines: BlameLine[];
+}
+
 export interface Breakpoint {
   id?: number;
   file: string;
@@ -85,12 +606,38 @@ export interface Breakpoint {
   verified: boolean;
 }
 
+export interface ExportedVariable {
+  type: string;
+  value: string;
+  children?: Record<string, ExportedVariable>;
+}
+
+export interface DebugStateSnapshot {
+  thread_id: number;
+  frame_id: number;
+  exported_at: string;
+  truncated: boolean;
+  scopes: Record<string, Record<string, ExportedVariable>>;
+}
+
+export interface BreakpointLineCheck {
+  line: number;
+  valid: boolean;
+  nearest?: number;
+}
+
+export interface DebugThread {
+  id: number;
+  name: string;
+}
+
 export interface StackFrame {
   id: number;
   name: string;
   file: string;
   line: number;
   column: number;
+  source_reference?: number;
 }
 
 export interface Variable {
@@ -98,6 +645,62 @@ export interface Variable {
   value: string;
   type: string;
   variables_reference: number;
+  indexed_variables?: number;
+  named_variables?: number;
+}
+
+export interface VariableRepr {
+  name: string;
+  repr: string;
+  indexed_variables?: number;
+  named_variables?: number;
+}
+
+export interface VariableInspection {
+  name: string;
+  expression: string;
+  value: string;
+  language: string;
+}
+
+export interface ExceptionBreakpointFilter {
+  filter: string;
+  label: string;
+  default: boolean;
+}
+
+export interface DebugCapabilities {
+  supports_conditional_breakpoints: boolean;
+  supports_hit_conditional_breakpoints: boolean;
+  supports_log_points: boolean;
+  supports_function_breakpoints: boolean;
+  supports_data_breakpoints: boolean;
+  supports_step_back: boolean;
+  supports_restart_frame: boolean;
+  supports_goto_targets_request: boolean;
+  supports_step_in_targets_request: boolean;
+  supports_set_variable: boolean;
+  supports_set_expression: boolean;
+  supports_completions_request: boolean;
+  supports_evaluate_for_hovers: boolean;
+  supports_clipboard_context: boolean;
+  supports_exception_info_request: boolean;
+  supports_terminate_request: boolean;
+  supports_restart_request: boolean;
+  exception_breakpoint_filters: ExceptionBreakpointFilter[];
+}
+
+export interface WatchResult {
+  expression: string;
+  result?: string;
+  error?: string;
+}
+
+export interface DataBreakpoint {
+  data_id: string;
+  description: string;
+  verified: boolean;
+  message?: string;
 }
 
 export interface Scope {
@@ -136,6 +739,39 @@ export class TauriAPI {
     return invoke('file_exists', { path });
   }
 
+  static async getAvailableDiskSpace(path: string): Promise<number> {
+    return invoke('get_available_disk_space', { path });
+  }
+
+  static async peekFile(path: string, bytes: number): Promise<FilePeek> {
+    return invoke('peek_file', { path, bytes });
+  }
+
+  static async formatConfigFile(path: string): Promise<ConfigFormatResult> {
+    return invoke('format_config_file', { path });
+  }
+
+  // App settings
+  static async getAppSettings(): Promise<AppSettings> {
+    return invoke('get_app_settings');
+  }
+
+  static async saveAppSettings(settings: AppSettings): Promise<void> {
+    return invoke('save_app_settings', { settings });
+  }
+
+  static async diagnoseUv(persist?: boolean): Promise<UvDiagnosis> {
+    return invoke('diagnose_uv', { persist });
+  }
+
+  static async addRecentFile(path: string): Promise<void> {
+    return invoke('add_recent_file', { path });
+  }
+
+  static async getRecentFiles(limit?: number): Promise<string[]> {
+    return invoke('get_recent_files', { limit });
+  }
+
   // Python/uv operations
   static async checkUvInstalled(): Promise<boolean> {
     return invoke('check_uv_installed');
@@ -145,27 +781,71 @@ export class TauriAPI {
     return invoke('ensure_uv_installed');
   }
 
+  static async checkUvVersion(): Promise<UvVersionInfo> {
+    return invoke('check_uv_version');
+  }
+
+  static async updateUv(): Promise<string> {
+    return invoke('update_uv');
+  }
+
+  static async listUvTools(): Promise<UvTool[]> {
+    return invoke('list_uv_tools');
+  }
+
+  static async installUvTool(name: string): Promise<string> {
+    return invoke('install_uv_tool', { name });
+  }
+
+  static async uninstallUvTool(name: string): Promise<string> {
+    return invoke('uninstall_uv_tool', { name });
+  }
+
   static async listPythonVersions(): Promise<string[]> {
     return invoke('list_python_versions');
   }
 
+  static async getAvailablePythonVersions(): Promise<PythonVersion[]> {
+    return invoke('get_available_python_versions');
+  }
+
+  static async discoverSystemPythons(): Promise<DiscoveredPython[]> {
+    return invoke('discover_system_pythons');
+  }
+
   static async installPythonVersion(version: string): Promise<string> {
     return invoke('install_python_version', { version });
   }
 
+  static async installRequiredPython(projectPath: string): Promise<string> {
+    return invoke('install_required_python', { projectPath });
+  }
+
   static async createVenv(projectPath: string, pythonVersion?: string): Promise<string> {
     return invoke('create_venv', { projectPath, pythonVersion });
   }
 
+  static async createNamedVenv(projectPath: string, name: string, pythonVersion?: string): Promise<string> {
+    return invoke('create_named_venv', { projectPath, name, pythonVersion });
+  }
+
+  static async listVenvs(projectPath: string): Promise<VenvInfo[]> {
+    return invoke('list_venvs', { projectPath });
+  }
+
   static async checkVenvExists(projectPath: string): Promise<boolean> {
     return invoke('check_venv_exists', { projectPath });
   }
 
-  static async installPackage(projectPath: string, packageName: string): Promise<string> {
+  static async installPackage(projectPath: string, packageName: string): Promise<UvOperationResult> {
     return invoke('install_package', { projectPath, package: packageName });
   }
 
-  static async uninstallPackage(projectPath: string, packageName: string): Promise<string> {
+  static async installPackages(projectPath: string, packages: string[]): Promise<BulkInstallResult> {
+    return invoke('install_packages', { projectPath, packages });
+  }
+
+  static async uninstallPackage(projectPath: string, packageName: string): Promise<UvOperationResult> {
     return invoke('uninstall_package', { projectPath, package: packageName });
   }
 
@@ -173,16 +853,43 @@ export class TauriAPI {
     return invoke('list_packages', { projectPath });
   }
 
+  static async getDependencyLicenses(projectPath: string): Promise<PackageLicense[]> {
+    return invoke('get_dependency_licenses', { projectPath });
+  }
+
   static async getDependencyTree(projectPath: string): Promise<DependencyTree> {
     return invoke('get_dependency_tree', { projectPath });
   }
 
+  static async getDependencyGraphDot(projectPath: string): Promise<string> {
+    return invoke('get_dependency_graph_dot', { projectPath });
+  }
+
+  static async importRequirementsToPyproject(projectPath: string): Promise<RequirementsImportResult> {
+    return invoke('import_requirements_to_pyproject', { projectPath });
+  }
+
+  static async diffAgainstRequirements(projectPath: string, requirementsPath: string): Promise<VersionMismatch[]> {
+    return invoke('diff_against_requirements', { projectPath, requirementsPath });
+  }
+
   static async runScript(projectPath: string, scriptPath: string): Promise<string> {
     return invoke('run_script', { projectPath, scriptPath });
   }
 
-  static async runScriptWithStreaming(projectPath: string, scriptPath: string): Promise<string> {
-    return invoke('run_script_with_output_streaming', { projectPath, scriptPath });
+  static async runScriptWithTraceback(projectPath: string, scriptPath: string): Promise<TracebackRunResult> {
+    return invoke('run_script_with_traceback', { projectPath, scriptPath });
+  }
+
+  static async runScriptWithStreaming(
+    projectPath: string,
+    scriptPath: string,
+    logFile?: string,
+    timestamp?: boolean,
+    rawOutput?: boolean,
+    parseLogLevels?: boolean
+  ): Promise<string> {
+    return invoke('run_script_with_output_streaming', { projectPath, scriptPath, logFile, timestamp, rawOutput, parseLogLevels });
   }
 
   static async runScriptSimple(projectPath: string, scriptPath: string): Promise<string> {
@@ -193,21 +900,97 @@ export class TauriAPI {
     return invoke('stop_running_script');
   }
 
+  static async getProcessStats(processId: number): Promise<ProcessStats> {
+    return invoke('get_process_stats', { processId });
+  }
+
+  static async getRunningProcessId(): Promise<number | null> {
+    return invoke('get_running_process_id');
+  }
+
+  static async profileScript(projectPath: string, scriptPath: string, limit?: number): Promise<ProfileEntry[]> {
+    return invoke('profile_script', { projectPath, scriptPath, limit });
+  }
+
   // UV Project Management
   static async initUvProject(projectPath: string, projectName: string, pythonVersion?: string): Promise<string> {
     return invoke('init_uv_project', { projectPath, projectName, pythonVersion });
   }
 
-  static async syncUvProject(projectPath: string): Promise<string> {
+  static async syncUvProject(projectPath: string): Promise<UvOperationResult> {
     return invoke('sync_uv_project', { projectPath });
   }
 
+  static async syncDryRun(projectPath: string): Promise<SyncAction[]> {
+    return invoke('sync_dry_run', { projectPath });
+  }
+
   static async runScriptWithUv(projectPath: string, scriptPath: string): Promise<string> {
     return invoke('run_script_with_uv', { projectPath, scriptPath });
   }
 
-  static async runScriptWithUvStreaming(projectPath: string, scriptPath: string): Promise<string> {
-    return invoke('run_script_with_uv_streaming', { projectPath, scriptPath });
+  static async evalSnippet(projectPath: string, code: string, timeoutSecs?: number): Promise<SnippetResult> {
+    return invoke('eval_snippet', { projectPath, code, timeoutSecs });
+  }
+
+  static async benchmarkSnippet(projectPath: string, setup: string, statement: string, number: number): Promise<BenchmarkResult> {
+    return invoke('benchmark_snippet', { projectPath, setup, statement, number });
+  }
+
+  static async profileImports(projectPath: string, module: string): Promise<ImportTiming[]> {
+    return invoke('profile_imports', { projectPath, module });
+  }
+
+  static async getPythonEnvironmentInfo(projectPath: string): Promise<PythonEnvironmentInfo> {
+    return invoke('get_python_environment_info', { projectPath });
+  }
+
+  static async runScriptPty(projectPath: string, scriptPath: string, id: string): Promise<string> {
+    return invoke('run_script_pty', { projectPath, scriptPath, id });
+  }
+
+  static async sendPtyInput(id: string, data: string): Promise<void> {
+    return invoke('send_pty_input', { id, data });
+  }
+
+  static async resizePty(id: string, rows: number, cols: number): Promise<void> {
+    return invoke('resize_pty', { id, rows, cols });
+  }
+
+  static async runScriptCapturingFigures(projectPath: string, scriptPath: string, outputDir?: string): Promise<FigureRunResult> {
+    return invoke('run_script_capturing_figures', { projectPath, scriptPath, outputDir });
+  }
+
+  static async runScriptWithMemory(projectPath: string, scriptPath: string): Promise<MemoryRunResult> {
+    return invoke('run_script_with_memory', { projectPath, scriptPath });
+  }
+
+  static async runScriptWithUvStreaming(projectPath: string, scriptPath: string, rawOutput?: boolean, parseLogLevels?: boolean): Promise<string> {
+    return invoke('run_script_with_uv_streaming', { projectPath, scriptPath, rawOutput, parseLogLevels });
+  }
+
+  static async runProjectScript(projectPath: string, scriptName: string, rawOutput?: boolean, parseLogLevels?: boolean): Promise<string> {
+    return invoke('run_project_script', { projectPath, scriptName, rawOutput, parseLogLevels });
+  }
+
+  static async runCommandStreaming(projectPath: string, program: string, args: string[], id: string): Promise<string> {
+    return invoke('run_command_streaming', { projectPath, program, args, id });
+  }
+
+  static async runScriptWatch(projectPath: string, scriptPath: string, watchPaths: string[], watchId: string): Promise<string> {
+    return invoke('run_script_watch', { projectPath, scriptPath, watchPaths, watchId });
+  }
+
+  static async stopScriptWatch(watchId: string): Promise<boolean> {
+    return invoke('stop_script_watch', { watchId });
+  }
+
+  static async startAutosync(projectPath: string, watchId: string): Promise<void> {
+    return invoke('start_autosync', { projectPath, watchId });
+  }
+
+  static async stopAutosync(watchId: string): Promise<boolean> {
+    return invoke('stop_autosync', { watchId });
   }
 
   // Project Management
@@ -226,6 +1009,54 @@ export class TauriAPI {
   static async saveProjectConfig(config: any): Promise<void> {
     return invoke('save_project_config', { config });
   }
+
+  static async setActiveVenv(projectPath: string, name: string | null): Promise<void> {
+    return invoke('set_active_venv', { projectPath, name });
+  }
+
+  static async saveSession(projectPath: string, session: EditorSession): Promise<void> {
+    return invoke('save_session', { projectPath, session });
+  }
+
+  static async loadSession(projectPath: string): Promise<EditorSession> {
+    return invoke('load_session', { projectPath });
+  }
+
+  static async saveWorkspace(path: string, workspace: Workspace): Promise<Workspace> {
+    return invoke('save_workspace', { path, workspace });
+  }
+
+  static async loadWorkspace(path: string): Promise<Workspace> {
+    return invoke('load_workspace', { path });
+  }
+
+  static async addProjectToWorkspace(path: string, projectPath: string): Promise<Workspace> {
+    return invoke('add_project_to_workspace', { path, projectPath });
+  }
+
+  static async removeProjectFromWorkspace(path: string, projectPath: string): Promise<Workspace> {
+    return invoke('remove_project_from_workspace', { path, projectPath });
+  }
+
+  static async notebookToScript(notebookPath: string, outputPath: string): Promise<string> {
+    return invoke('notebook_to_script', { notebookPath, outputPath });
+  }
+
+  static async scriptToNotebook(scriptPath: string, outputPath: string): Promise<string> {
+    return invoke('script_to_notebook', { scriptPath, outputPath });
+  }
+
+  static async createScratchFile(extension: string = 'py'): Promise<string> {
+    return invoke('create_scratch_file', { extension });
+  }
+
+  static async listScratchFiles(): Promise<ScratchFile[]> {
+    return invoke('list_scratch_files');
+  }
+
+  static async deleteScratchFile(path: string): Promise<void> {
+    return invoke('delete_scratch_file', { path });
+  }
   
   static async getRecentProjects(): Promise<any[]> {
     return invoke('get_recent_projects');
@@ -248,6 +1079,18 @@ export class TauriAPI {
     return invoke('ruff_check_project', { projectPath });
   }
 
+  static async ruffCheckChanged(projectPath: string): Promise<RuffCheckResult> {
+    return invoke('ruff_check_changed', { projectPath });
+  }
+
+  static async ruffCheckProjectStreaming(projectPath: string): Promise<RuffCheckResult> {
+    return invoke('ruff_check_project_streaming', { projectPath });
+  }
+
+  static async checkPythonCompatibility(projectPath: string, targetVersion: string): Promise<CompatibilityResult> {
+    return invoke('check_python_compatibility', { projectPath, targetVersion });
+  }
+
   static async ruffFormatFile(projectPath: string, filePath: string): Promise<string> {
     return invoke('ruff_format_file', { projectPath, filePath });
   }
@@ -256,14 +1099,112 @@ export class TauriAPI {
     return invoke('ruff_format_project', { projectPath });
   }
 
+  static async ruffFormatProjectCheck(projectPath: string): Promise<string[]> {
+    return invoke('ruff_format_project_check', { projectPath });
+  }
+
   static async ruffFixFile(projectPath: string, filePath: string): Promise<RuffCheckResult> {
     return invoke('ruff_fix_file', { projectPath, filePath });
   }
 
+  static async fixAndFormatFile(projectPath: string, filePath: string): Promise<FixAndFormatResult> {
+    return invoke('fix_and_format_file', { projectPath, filePath });
+  }
+
+  static async cleanupImports(projectPath: string, filePath: string): Promise<ImportCleanupResult> {
+    return invoke('cleanup_imports', { projectPath, filePath });
+  }
+
+  static async getAvailableFixes(projectPath: string, filePath: string): Promise<RuffFix[]> {
+    return invoke('get_available_fixes', { projectPath, filePath });
+  }
+
+  static async applyFix(projectPath: string, filePath: string, rule: string, line?: number): Promise<RuffCheckResult> {
+    return invoke('apply_fix', { projectPath, filePath, rule, line });
+  }
+
+  static async addNoqa(filePath: string, line: number, ruleCode: string): Promise<string> {
+    return invoke('add_noqa', { filePath, line, ruleCode });
+  }
+
+  static async startRuffWatch(projectPath: string, watchId: string): Promise<void> {
+    return invoke('start_ruff_watch', { projectPath, watchId });
+  }
+
+  static async stopRuffWatch(watchId: string): Promise<boolean> {
+    return invoke('stop_ruff_watch', { watchId });
+  }
+
   static async createRuffConfig(projectPath: string): Promise<string> {
     return invoke('create_ruff_config', { projectPath });
   }
 
+  // Mypy operations
+  static async mypyCheckProject(projectPath: string): Promise<MypyProjectResult> {
+    return invoke('mypy_check_project', { projectPath });
+  }
+
+  static async isortFile(projectPath: string, filePath: string): Promise<string> {
+    return invoke('isort_file', { projectPath, filePath });
+  }
+
+  static async isortCheck(projectPath: string, filePath: string): Promise<IsortCheckResult> {
+    return invoke('isort_check', { projectPath, filePath });
+  }
+
+  // Search operations
+  static async scanTodos(projectPath: string, tags?: string[]): Promise<TodoItem[]> {
+    return invoke('scan_todos', { projectPath, tags });
+  }
+
+  static async findImporters(projectPath: string, moduleName: string): Promise<ImportLocation[]> {
+    return invoke('find_importers', { projectPath, moduleName });
+  }
+
+  static async getDocumentSymbols(path: string): Promise<DocumentSymbol[]> {
+    return invoke('get_document_symbols', { path });
+  }
+
+  static async renameSymbol(path: string, line: number, column: number, newName: string): Promise<TextEdit[]> {
+    return invoke('rename_symbol', { path, line, column, newName });
+  }
+
+  static async toggleLineComment(content: string, startLine: number, endLine: number): Promise<string> {
+    return invoke('toggle_line_comment', { content, startLine, endLine });
+  }
+
+  static async detectIndentation(path: string): Promise<IndentationInfo> {
+    return invoke('detect_indentation', { path });
+  }
+
+  static async normalizeFile(path: string, trimTrailing: boolean, ensureFinalNewline: boolean, lineEnding: 'lf' | 'crlf' | 'keep'): Promise<number> {
+    return invoke('normalize_file', { path, trimTrailing, ensureFinalNewline, lineEnding });
+  }
+
+  static async syntaxCheckFile(projectPath: string, filePath: string): Promise<SyntaxCheckResult> {
+    return invoke('syntax_check_file', { projectPath, filePath });
+  }
+
+  static async syntaxCheckProject(projectPath: string): Promise<FileSyntaxError[]> {
+    return invoke('syntax_check_project', { projectPath });
+  }
+
+  static async getCompletions(projectPath: string, filePath: string, line: number, column: number, source: string): Promise<Completion[]> {
+    return invoke('get_completions', { projectPath, filePath, line, column, source });
+  }
+
+  static async getHoverInfo(projectPath: string, filePath: string, line: number, column: number, source: string): Promise<HoverInfo | null> {
+    return invoke('get_hover_info', { projectPath, filePath, line, column, source });
+  }
+
+  static async gotoDefinition(projectPath: string, filePath: string, line: number, column: number, source: string): Promise<DefinitionResult> {
+    return invoke('goto_definition', { projectPath, filePath, line, column, source });
+  }
+
+  static async resetEnvironmentCache(projectPath: string): Promise<void> {
+    return invoke('reset_environment_cache', { projectPath });
+  }
+
   // Template operations
   static async getProjectTemplates(): Promise<ProjectTemplate[]> {
     return invoke('get_project_templates');
@@ -273,6 +1214,10 @@ export class TauriAPI {
     return invoke('create_project_from_template', { projectPath, templateId, projectName, pythonVersion });
   }
 
+  static async createProjectFromGit(gitUrl: string, destination: string, initVenv: boolean): Promise<GitProjectResult> {
+    return invoke('create_project_from_git', { gitUrl, destination, initVenv });
+  }
+
   // PyProject.toml management
   static async readPyProjectToml(projectPath: string): Promise<PyProjectToml> {
     return invoke('read_pyproject_toml', { projectPath });
@@ -286,20 +1231,120 @@ export class TauriAPI {
     return invoke('check_pyproject_exists', { projectPath });
   }
 
+  static async getLockfileHash(projectPath: string): Promise<LockfileHash> {
+    return invoke('get_lockfile_hash', { projectPath });
+  }
+
+  static async renameProject(projectPath: string, newName: string, renameDirectory: boolean): Promise<RenameProjectResult> {
+    return invoke('rename_project', { projectPath, newName, renameDirectory });
+  }
+
+  static async detectRunConfig(projectPath: string): Promise<RunSuggestion[]> {
+    return invoke('detect_run_config', { projectPath });
+  }
+
+  static async saveRunConfig(projectPath: string, config: RunConfig): Promise<RunConfig[]> {
+    return invoke('save_run_config', { projectPath, config });
+  }
+
+  static async listRunConfigs(projectPath: string): Promise<RunConfig[]> {
+    return invoke('list_run_configs', { projectPath });
+  }
+
+  static async deleteRunConfig(projectPath: string, name: string): Promise<RunConfig[]> {
+    return invoke('delete_run_config', { projectPath, name });
+  }
+
+  static async generateReadme(projectPath: string, write = false, overwrite = false): Promise<GeneratedReadme> {
+    return invoke('generate_readme', { projectPath, write, overwrite });
+  }
+
+  // Git operations
+  static async readGitignore(projectPath: string): Promise<string[]> {
+    return invoke('read_gitignore', { projectPath });
+  }
+
+  static async addGitignoreEntry(projectPath: string, pattern: string): Promise<boolean> {
+    return invoke('add_gitignore_entry', { projectPath, pattern });
+  }
+
+  static async getIgnoredFiles(projectPath: string, paths: string[]): Promise<string[]> {
+    return invoke('get_ignored_files', { projectPath, paths });
+  }
+
+  static async gitBranchInfo(projectPath: string): Promise<GitBranchInfo> {
+    return invoke('git_branch_info', { projectPath });
+  }
+
+  static async gitStageFile(projectPath: string, path: string): Promise<void> {
+    return invoke('git_stage_file', { projectPath, path });
+  }
+
+  static async gitUnstageFile(projectPath: string, path: string): Promise<void> {
+    return invoke('git_unstage_file', { projectPath, path });
+  }
+
+  static async gitDiscardChanges(projectPath: string, path: string, confirm: boolean): Promise<void> {
+    return invoke('git_discard_changes', { projectPath, path, confirm });
+  }
+
+  static async gitFileDiffContent(projectPath: string, path: string): Promise<FileDiffContent> {
+    return invoke('git_file_diff_content', { projectPath, path });
+  }
+
+  static async gitBlame(projectPath: string, filePath: string): Promise<FileBlame> {
+    return invoke('git_blame', { projectPath, filePath });
+  }
+
+  static async gitFileHistory(projectPath: string, filePath: string, limit?: number): Promise<GitCommit[]> {
+    return invoke('git_file_history', { projectPath, filePath, limit });
+  }
+
+  static async gitRestoreFileVersion(projectPath: string, filePath: string, commitHash: string): Promise<string> {
+    return invoke('git_restore_file_version', { projectPath, filePath, commitHash });
+  }
+
+  static async gitCheckoutFileVersion(projectPath: string, filePath: string, commitHash: string, confirm: boolean): Promise<void> {
+    return invoke('git_checkout_file_version', { projectPath, filePath, commitHash, confirm });
+  }
+
+  static async setupGitHooks(projectPath: string, runRuff: boolean, runTests: boolean): Promise<string> {
+    return invoke('setup_git_hooks', { projectPath, runRuff, runTests });
+  }
+
+  // Test operations
+  static async runSingleTest(projectPath: string, nodeId: string): Promise<PytestReport> {
+    return invoke('run_single_test', { projectPath, nodeId });
+  }
+
+  static async discoverTests(projectPath: string): Promise<TestDiscovery> {
+    return invoke('discover_tests', { projectPath });
+  }
+
+  static async runDoctests(projectPath: string, filePath: string): Promise<DoctestReport> {
+    return invoke('run_doctests', { projectPath, filePath });
+  }
+
   // Debug operations
   static async startDebugSession(
       projectPath: string,
       scriptPath: string,
-      breakpoints: Breakpoint[]
+      breakpoints: Breakpoint[],
+      stopOnEntry: boolean = false
     ): Promise<string> {
-    console.debug('[TauriAPI] startDebugSession payload', { projectPath, scriptPath, breakpoints })
+    console.debug('[TauriAPI] startDebugSession payload', { projectPath, scriptPath, breakpoints, stopOnEntry })
     return invoke('start_debug_session', {
       projectPath,
       scriptPath,
-      breakpoints
+      breakpoints,
+      stopOnEntry
     })
   }
 
+  static async attachDebugSession(host: string, port: number, breakpoints: Breakpoint[]): Promise<string> {
+    return invoke('attach_debug_session', { host, port, breakpoints });
+  }
+
   static async debugContinue(threadId: number): Promise<void> {
     return invoke('debug_continue', { threadId });
   }
@@ -316,22 +1361,66 @@ export class TauriAPI {
     return invoke('debug_step_out', { threadId });
   }
 
+  static async getThreads(): Promise<DebugThread[]> {
+    return invoke('get_threads');
+  }
+
   static async getStackTrace(threadId: number): Promise<StackFrame[]> {
     return invoke('get_stack_trace', { threadId });
   }
 
+  static async getSource(sourceReference?: number, path?: string): Promise<string> {
+    return invoke('get_source', { sourceReference, path });
+  }
+
   static async getScopes(frameId: number): Promise<Scope[]> {
     return invoke('get_scopes', { frameId });
   }
 
-  static async getVariables(variablesReference: number): Promise<Variable[]> {
-    return invoke('get_variables', { variablesReference });
+  static async getVariables(variablesReference: number, start?: number, count?: number): Promise<Variable[]> {
+    return invoke('get_variables', { variablesReference, start, count });
+  }
+
+  static async getVariableRepr(variablesReference: number, name: string, frameId?: number): Promise<VariableRepr> {
+    return invoke('get_variable_repr', { variablesReference, name, frameId });
+  }
+
+  static async inspectVariable(variablesReference: number, name: string, frameId: number): Promise<VariableInspection> {
+    return invoke('inspect_variable', { variablesReference, name, frameId });
+  }
+
+  static async getDebugCapabilities(): Promise<DebugCapabilities> {
+    return invoke('get_debug_capabilities');
+  }
+
+  static async evaluateWatches(expressions: string[], frameId: number): Promise<WatchResult[]> {
+    return invoke('evaluate_watches', { expressions, frameId });
+  }
+
+  static async setDataBreakpoint(variablesReference: number, name: string): Promise<DataBreakpoint> {
+    return invoke('set_data_breakpoint', { variablesReference, name });
   }
 
   static async stopDebugSession(): Promise<void> {
     return invoke('stop_debug_session');
   }
 
+  static async exportDebugState(threadId: number, frameId: number): Promise<DebugStateSnapshot> {
+    return invoke('export_debug_state', { threadId, frameId });
+  }
+
+  static async validateBreakpointLines(filePath: string, lines: number[]): Promise<BreakpointLineCheck[]> {
+    return invoke('validate_breakpoint_lines', { filePath, lines });
+  }
+
+  static async isPortAvailable(port: number): Promise<boolean> {
+    return invoke('is_port_available', { port });
+  }
+
+  static async findFreePort(start: number): Promise<number> {
+    return invoke('find_free_port', { start });
+  }
+
   // File dialog operations
   static async openFileDialog(): Promise<string | null> {
     return invoke('open_file_dialog');